@@ -0,0 +1,91 @@
+//! Interpreter benchmarks. Run with `cargo bench -p karamellib --features bench`.
+//!
+//! Every program is compiled once and executed by `run_vm` on each iteration.
+//! Programs have fixed inputs and do not write to stdout, so results are
+//! comparable between runs.
+
+extern crate karamellib;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use karamellib::parser::*;
+use karamellib::compiler::*;
+use karamellib::vm::*;
+use karamellib::syntax::*;
+
+struct Benchmark {
+    name: &'static str,
+    code: &'static str
+}
+
+const BENCHMARKS: [Benchmark; 4] = [
+    Benchmark {
+        name: "aritmetik_döngü",
+        code: r#"
+toplam = 0
+sayaç = 0
+döngü sayaç != 10000:
+    toplam = toplam + sayaç * 2 - 1
+    sayaç += 1
+"#
+    },
+    Benchmark {
+        name: "yazı_birleştirme",
+        code: r#"
+metin = ''
+sayaç = 0
+döngü sayaç != 1000:
+    metin = metin + 'karamel'
+    sayaç += 1
+"#
+    },
+    Benchmark {
+        name: "liste_oluşturma",
+        code: r#"
+sayaç = 0
+döngü sayaç != 2000:
+    elemanlar = [sayaç, sayaç + 1, sayaç + 2, 'karamel']
+    sayaç += 1
+"#
+    },
+    Benchmark {
+        name: "fonksiyon_çağrısı",
+        code: r#"
+fonk topla(bir, iki): döndür bir + iki
+toplam = 0
+sayaç = 0
+döngü sayaç != 5000:
+    toplam = topla(toplam, sayaç)
+    sayaç += 1
+"#
+    }
+];
+
+fn compile(code: &str) -> KaramelCompilerContext {
+    let mut parser = Parser::new(code);
+    parser.parse().expect("Benchmark kodu okunamadı");
+
+    let syntax = SyntaxParser::new(parser.tokens().to_vec());
+    let ast = syntax.parse().expect("Benchmark kodu ayrıştırılamadı");
+
+    let mut context = KaramelCompilerContext::new();
+    let opcode_compiler = InterpreterCompiler {};
+    opcode_compiler.compile(ast, &mut context).expect("Benchmark kodu derlenemedi");
+    context
+}
+
+fn execute(context: &mut KaramelCompilerContext) {
+    /* Start from the first opcode again, variables are initialized by the program itself */
+    context.opcodes_ptr = context.opcodes_top_ptr;
+    unsafe { interpreter::run_vm(context, false, false) }.expect("Benchmark kodu çalıştırılamadı");
+}
+
+fn interpreter_benchmarks(criterion: &mut Criterion) {
+    for benchmark in BENCHMARKS.iter() {
+        let mut context = compile(benchmark.code);
+        criterion.bench_function(benchmark.name, |bencher| bencher.iter(|| execute(&mut context)));
+    }
+}
+
+criterion_group!(benches, interpreter_benchmarks);
+criterion_main!(benches);
@@ -3,7 +3,8 @@ use crate::types::VmObject;
 use crate::buildin::{Module, Class};
 use crate::compiler::GetType;
 use crate::error::KaramelErrorType;
-use crate::{n_parameter_expected};
+use crate::compiler::value::KaramelPrimative;
+use crate::{n_parameter_expected, expected_parameter_type};
 use std::{cell::RefCell, collections::HashMap};
 use std::rc::Rc;
 
@@ -58,6 +59,12 @@ impl BaseFunctionsModule  {
 
         let rc_module = Rc::new(module);
         rc_module.methods.borrow_mut().insert("tür_bilgisi".to_string(), FunctionReference::native_function(Self::type_info as NativeCall, "tür_bilgisi".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("elemanlar".to_string(), FunctionReference::native_function(Self::items as NativeCall, "elemanlar".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("çiftler".to_string(), FunctionReference::native_function(Self::pairs as NativeCall, "çiftler".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("ciftler".to_string(), FunctionReference::native_function(Self::pairs as NativeCall, "ciftler".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("eşleştir".to_string(), FunctionReference::native_function(Self::zip as NativeCall, "eşleştir".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("eslestir".to_string(), FunctionReference::native_function(Self::zip as NativeCall, "eslestir".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("kopyala".to_string(), FunctionReference::native_function(Self::copy as NativeCall, "kopyala".to_string(), rc_module.clone()));
         rc_module
     }
 
@@ -71,4 +78,85 @@ impl BaseFunctionsModule  {
             None => Ok(EMPTY_OBJECT)
         }
     }
+
+    /// Items used by 'her' loop. Lists are copied, dictionaries return their keys and texts return their characters.
+    pub fn items(parameter: FunctionParameter) -> NativeCallResult {
+        if parameter.length() != 1 {
+            return n_parameter_expected!("elemanlar".to_string(), 1, parameter.length());
+        }
+
+        match &*parameter.iter().next().unwrap().deref() {
+            KaramelPrimative::List(list) => Ok(VmObject::from(list.borrow().clone())),
+            KaramelPrimative::Dict(dict) => Ok(VmObject::from(dict.borrow().keys().map(|key| VmObject::from(key.to_string())).collect::<Vec<_>>())),
+            KaramelPrimative::Text(text) => Ok(VmObject::from(text.chars().map(|ch| VmObject::from(ch.to_string())).collect::<Vec<_>>())),
+            _ => expected_parameter_type!("elemanlar".to_string(), "Liste, Sözlük veya Yazı".to_string())
+        }
+    }
+
+    /// Pairs used by two variables 'her' loop. Dictionaries return [key, value] and lists return [index, value] pairs.
+    pub fn pairs(parameter: FunctionParameter) -> NativeCallResult {
+        if parameter.length() != 1 {
+            return n_parameter_expected!("çiftler".to_string(), 1, parameter.length());
+        }
+
+        match &*parameter.iter().next().unwrap().deref() {
+            KaramelPrimative::List(list) => Ok(VmObject::from(list.borrow().iter().enumerate().map(|(index, item)| VmObject::from(vec![VmObject::from(index), *item])).collect::<Vec<_>>())),
+            KaramelPrimative::Dict(dict) => Ok(VmObject::from(dict.borrow().iter().map(|(key, item)| VmObject::from(vec![VmObject::from(key.to_string()), *item])).collect::<Vec<_>>())),
+            _ => expected_parameter_type!("çiftler".to_string(), "Liste veya Sözlük".to_string())
+        }
+    }
+
+    /// Pairs the elements of two lists at the same index, extra elements of the longer list are ignored.
+    pub fn zip(parameter: FunctionParameter) -> NativeCallResult {
+        if parameter.length() != 2 {
+            return n_parameter_expected!("eşleştir".to_string(), 2, parameter.length());
+        }
+
+        let mut arguments = parameter.iter();
+        match (&*arguments.next().unwrap().deref(), &*arguments.next().unwrap().deref()) {
+            (KaramelPrimative::List(left), KaramelPrimative::List(right)) => Ok(VmObject::from(left.borrow().iter().zip(right.borrow().iter()).map(|(left_item, right_item)| VmObject::from(vec![*left_item, *right_item])).collect::<Vec<_>>())),
+            _ => expected_parameter_type!("eşleştir".to_string(), "Liste".to_string())
+        }
+    }
+
+    /// Independent copy of the value, lists and dictionaries are copied with all of their inner lists and dictionaries.
+    pub fn copy(parameter: FunctionParameter) -> NativeCallResult {
+        if parameter.length() != 1 {
+            return n_parameter_expected!("kopyala".to_string(), 1, parameter.length());
+        }
+
+        Ok(Self::deep_copy(*parameter.iter().next().unwrap(), &mut HashMap::new()))
+    }
+
+    /// Copies are kept by the address of their source, a list that contains itself is copied as a list that contains the copy.
+    fn deep_copy(value: VmObject, copies: &mut HashMap<*const KaramelPrimative, VmObject>) -> VmObject {
+        let source = value.deref();
+        if let Some(copy) = copies.get(&Rc::as_ptr(&source)) {
+            return *copy;
+        }
+
+        match &*source {
+            KaramelPrimative::List(list) => {
+                let copy = VmObject::from(Vec::new());
+                copies.insert(Rc::as_ptr(&source), copy);
+
+                let items = list.borrow().iter().map(|item| Self::deep_copy(*item, copies)).collect::<Vec<_>>();
+                if let KaramelPrimative::List(copy_items) = &*copy.deref() {
+                    *copy_items.borrow_mut() = items;
+                }
+                copy
+            },
+            KaramelPrimative::Dict(dict) => {
+                let copy = VmObject::from(HashMap::new());
+                copies.insert(Rc::as_ptr(&source), copy);
+
+                let items = dict.borrow().iter().map(|(key, item)| (key.to_string(), Self::deep_copy(*item, copies))).collect::<HashMap<_, _>>();
+                if let KaramelPrimative::Dict(copy_items) = &*copy.deref() {
+                    *copy_items.borrow_mut() = items;
+                }
+                copy
+            },
+            _ => value
+        }
+    }
 }
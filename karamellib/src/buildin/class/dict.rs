@@ -7,7 +7,7 @@ use crate::buildin::class::baseclass::BasicInnerClass;
 use crate::compiler::value::KaramelPrimative;
 use crate::error::KaramelErrorType;
 use crate::types::VmObject;
-use crate::{n_parameter_expected, expected_parameter_type, arc_bool, primative_list};
+use crate::{n_parameter_expected, expected_parameter_type, primative_list};
 
 use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
 
@@ -35,6 +35,11 @@ impl DictClass {
         dict.add_class_method("temizle", clear);
         dict.add_class_method("sil", remove);
         dict.add_class_method("anahtarlar", keys);
+        dict.add_class_method("değerler", values);
+        dict.add_class_method("degerler", values);
+        dict.add_class_method("birleştir", merge);
+        dict.add_class_method("birlestir", merge);
+        dict.add_class_method("al", get_or_default);
 
         PRIMATIVE_CLASS_NAMES.lock().unwrap().insert(dict.get_type());
 
@@ -176,6 +181,8 @@ fn clear(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+/// Dictionaries are shared by reference and mutated in place through their
+/// `RefCell`, so every variable pointing to the same dictionary sees the removal.
 fn remove(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
         return match parameter.length() {
@@ -187,8 +194,8 @@ fn remove(parameter: FunctionParameter) -> NativeCallResult {
                 };
                 
                 Ok(match dict.borrow_mut().remove(&key.to_string()) {
-                    Some(_) => arc_bool!(true),
-                    None => arc_bool!(false)
+                    Some(item) => item,
+                    None => EMPTY_OBJECT
                 })
             },
             _ => n_parameter_expected!("sil".to_string(), 1, parameter.length())
@@ -210,6 +217,16 @@ fn keys(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+/// Values are returned in the same order with `anahtarlar`.
+fn values(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
+        let values = dict.borrow().values().cloned().collect::<Vec<_>>();
+        return Ok(VmObject::native_convert(primative_list!(values)));
+    }
+
+    Ok(EMPTY_OBJECT)
+}
+
 fn contains(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
         return match parameter.length() {
@@ -226,6 +243,54 @@ fn contains(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+fn merge(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 =>  n_parameter_expected!("birleştir".to_string(), 1),
+            1 => {
+                match &*parameter.iter().next().unwrap().deref() {
+                    KaramelPrimative::Dict(other) =>  {
+                        let mut merged = dict.borrow().clone();
+                        for (key, value) in other.borrow().iter() {
+                            merged.insert(key.to_string(), *value);
+                        }
+                        Ok(VmObject::from(merged))
+                    },
+                    _ => expected_parameter_type!("birleştir".to_string(), "Sözlük".to_string())
+                }
+            },
+            _ => n_parameter_expected!("birleştir".to_string(), 1, parameter.length())
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn get_or_default(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 =>  n_parameter_expected!("al".to_string(), 2),
+            1 | 2 => {
+                let mut iter = parameter.iter();
+                let key = match &*iter.next().unwrap().deref() {
+                    KaramelPrimative::Text(text) => text.clone(),
+                    _ => return expected_parameter_type!("al".to_string(), "Yazı".to_string())
+                };
+                let default = match iter.next() {
+                    Some(default) => *default,
+                    None => EMPTY_OBJECT
+                };
+
+                return match dict.borrow().get(&*key) {
+                    Some(item) => Ok(*item),
+                    _ => Ok(default)
+                };
+            },
+            _ => n_parameter_expected!("al".to_string(), 2, parameter.length())
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
 impl DictClass {
     pub fn add_static_method(&mut self, name: &str, function: NativeCall) {
         self.base.add_method(name, function, FunctionFlag::IN_CLASS & FunctionFlag::STATIC);
@@ -234,4 +299,60 @@ impl DictClass {
     pub fn add_class_method(&mut self, name: &str, function: NativeCall) {
         self.base.add_method(name, function, FunctionFlag::IN_CLASS);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use std::collections::HashMap;
+    use crate::compiler::value::KaramelPrimative;
+    use super::*;
+
+    use crate::nativecall_test;
+    use crate::nativecall_test_with_params;
+    use crate::arc_number;
+    use crate::arc_text;
+    use crate::primative_text;
+    use crate::primative_number;
+
+    fn dict_from(items: &[(&str, VmObject)]) -> KaramelPrimative {
+        let mut dict = HashMap::new();
+        for (key, value) in items.iter() {
+            dict.insert(key.to_string(), *value);
+        }
+        KaramelPrimative::Dict(RefCell::new(dict))
+    }
+
+    nativecall_test!{test_keys_1, keys, dict_from(&[("a", arc_number!(1))]), primative_list!(vec![arc_text!("a")])}
+    nativecall_test!{test_keys_2, keys, dict_from(&[]), primative_list!(Vec::new())}
+    nativecall_test!{test_values_1, values, dict_from(&[("a", arc_number!(1))]), primative_list!(vec![arc_number!(1)])}
+    nativecall_test!{test_values_2, values, dict_from(&[]), primative_list!(Vec::new())}
+    nativecall_test!{test_length_1, length, dict_from(&[("a", arc_number!(1)), ("b", arc_number!(2))]), primative_number!(2)}
+    nativecall_test_with_params!{test_contains_1, contains, dict_from(&[("a", arc_number!(1))]), [arc_text!("a")], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_contains_2, contains, dict_from(&[("a", arc_number!(1))]), [arc_text!("b")], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_merge_1, merge, dict_from(&[("a", arc_number!(1)), ("b", arc_number!(2))]), [VmObject::native_convert(dict_from(&[("b", arc_number!(3)), ("c", arc_number!(4))]))], dict_from(&[("a", arc_number!(1)), ("b", arc_number!(3)), ("c", arc_number!(4))])}
+    nativecall_test_with_params!{test_merge_2, merge, dict_from(&[]), [VmObject::native_convert(dict_from(&[("a", arc_number!(1))]))], dict_from(&[("a", arc_number!(1))])}
+    nativecall_test_with_params!{test_merge_3, merge, dict_from(&[("a", arc_number!(1))]), [VmObject::native_convert(dict_from(&[]))], dict_from(&[("a", arc_number!(1))])}
+
+    nativecall_test_with_params!{test_get_or_default_1, get_or_default, dict_from(&[("a", arc_number!(1))]), [arc_text!("a"), arc_number!(10)], primative_number!(1)}
+    nativecall_test_with_params!{test_get_or_default_2, get_or_default, dict_from(&[("a", arc_number!(1))]), [arc_text!("b"), arc_number!(10)], primative_number!(10)}
+    nativecall_test_with_params!{test_get_or_default_3, get_or_default, dict_from(&[("a", arc_number!(1))]), [arc_text!("b")], KaramelPrimative::Empty}
+    nativecall_test_with_params!{test_get_or_default_4, get_or_default, dict_from(&[("a", EMPTY_OBJECT)]), [arc_text!("a"), arc_text!("yok")], KaramelPrimative::Empty}
+    nativecall_test_with_params!{test_get_or_default_5, get_or_default, dict_from(&[]), [arc_text!("a"), arc_text!("yok")], primative_text!("yok")}
+
+    #[test]
+    fn test_remove_1() {
+        let stdout = Some(RefCell::new(String::new()));
+        let stderr = Some(RefCell::new(String::new()));
+        let dict = Rc::new(dict_from(&[("a", arc_number!(1)), ("b", arc_number!(2))]));
+        let obj = VmObject::native_convert_by_ref(dict.clone());
+
+        let result = remove(FunctionParameter::new(&[arc_text!("a")].to_vec(), Some(obj), 1 as usize, 1 as u8, &stdout, &stderr));
+        assert_eq!(*result.unwrap().deref(), primative_number!(1));
+        assert_eq!(*dict, dict_from(&[("b", arc_number!(2))]));
+
+        let result = remove(FunctionParameter::new(&[arc_text!("c")].to_vec(), Some(obj), 1 as usize, 1 as u8, &stdout, &stderr));
+        assert_eq!(*result.unwrap().deref(), KaramelPrimative::Empty);
+        assert_eq!(*dict, dict_from(&[("b", arc_number!(2))]));
+    }
+}
@@ -21,7 +21,24 @@ pub fn get_primative_class() -> Rc<dyn Class> {
     opcode.add_class_method("temizle", clear);
     opcode.add_class_method("arayaekle", insert);
     opcode.add_class_method("pop", pop);
+    opcode.add_class_method("çıkar", pop);
+    opcode.add_class_method("cikar", pop);
     opcode.add_class_method("sil", remove);
+    opcode.add_class_method("benzersiz", unique);
+    opcode.add_class_method("düzleştir", flatten);
+    opcode.add_class_method("duzlestir", flatten);
+    opcode.add_class_method("say", count);
+    opcode.add_class_method("min", min);
+    opcode.add_class_method("maks", max);
+    opcode.add_class_method("topla", sum);
+    opcode.add_class_method("dilim", slice);
+    opcode.add_class_method("hepsi", all);
+    opcode.add_class_method("herhangi", any);
+    opcode.add_class_method("indeksle", enumerate);
+    opcode.add_class_method("sırala", sort);
+    opcode.add_class_method("sirala", sort);
+    opcode.add_class_method("harita", map);
+    opcode.add_class_method("filtrele", filter);
     opcode.set_getter(getter);
     opcode.set_setter(setter);
 
@@ -209,6 +226,260 @@ fn pop(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+fn unique(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let mut items: Vec<VmObject> = Vec::new();
+        for item in list.borrow().iter() {
+            let value = item.deref();
+            if items.iter().all(|added| added.deref() != value) {
+                items.push(*item);
+            }
+        }
+        return Ok(VmObject::from(items));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn enumerate(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let items = list.borrow().iter()
+            .enumerate()
+            .map(|(index, item)| VmObject::from(vec![VmObject::from(index as f64), *item]))
+            .collect::<Vec<VmObject>>();
+        return Ok(VmObject::from(items));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn flatten(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let mut items: Vec<VmObject> = Vec::new();
+        for item in list.borrow().iter() {
+            match &*item.deref() {
+                KaramelPrimative::List(inner_list) => items.extend(inner_list.borrow().iter()),
+                _ => items.push(*item)
+            };
+        }
+        return Ok(VmObject::from(items));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn count(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 =>  n_parameter_expected!("say".to_string(), 1),
+            1 => {
+                let search = parameter.iter().next().unwrap().deref();
+                let total = list.borrow().iter().filter(|item| item.deref() == search).count();
+                Ok(VmObject::from(total))
+            },
+            _ => n_parameter_expected!("say".to_string(), 1, parameter.length())
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn numbers(list: &[VmObject], function_name: &str) -> Result<Vec<f64>, KaramelErrorType> {
+    let mut numbers = Vec::with_capacity(list.len());
+    for item in list.iter() {
+        match item.as_number() {
+            Some(number) => numbers.push(number),
+            None => return Err(KaramelErrorType::ListElementTypeNotValid {
+                function: function_name.to_string(),
+                expected: "Sayı".to_string()
+            })
+        };
+    }
+    Ok(numbers)
+}
+
+fn min(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let numbers = numbers(&list.borrow(), "min")?;
+        return match numbers.into_iter().reduce(f64::min) {
+            Some(number) => Ok(VmObject::from(number)),
+            None => Err(KaramelErrorType::ListIsEmpty("min".to_string()))
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn max(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let numbers = numbers(&list.borrow(), "maks")?;
+        return match numbers.into_iter().reduce(f64::max) {
+            Some(number) => Ok(VmObject::from(number)),
+            None => Err(KaramelErrorType::ListIsEmpty("maks".to_string()))
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn sum(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let numbers = numbers(&list.borrow(), "topla")?;
+        return Ok(VmObject::from(numbers.iter().sum::<f64>()));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+/// Returns a new sorted list, the original list is not changed. Only lists of
+/// numbers or lists of texts can be sorted.
+fn sort(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let descending = match parameter.length() {
+            0 => false,
+            1 => match &*parameter.iter().next().unwrap().deref() {
+                KaramelPrimative::Bool(descending) => *descending,
+                _ => return expected_parameter_type!("sırala".to_string(), "Bool".to_string())
+            },
+            _ => return n_parameter_expected!("sırala".to_string(), 1, parameter.length())
+        };
+
+        let mut items = list.borrow().clone();
+        if items.iter().all(|item| item.as_number().is_some()) {
+            items.sort_by(|left, right| left.as_number().unwrap().total_cmp(&right.as_number().unwrap()));
+        }
+        else if items.iter().all(|item| matches!(&*item.deref(), KaramelPrimative::Text(_))) {
+            items.sort_by(|left, right| match (&*left.deref(), &*right.deref()) {
+                (KaramelPrimative::Text(left), KaramelPrimative::Text(right)) => left.cmp(right),
+                _ => std::cmp::Ordering::Equal
+            });
+        }
+        else {
+            return Err(KaramelErrorType::MixedListCannotBeSorted("sırala".to_string()));
+        }
+
+        if descending {
+            items.reverse();
+        }
+        return Ok(VmObject::from(items));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn slice(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let mut arguments = Vec::with_capacity(3);
+        match parameter.length() {
+            2 | 3 => {
+                for argument in parameter.iter() {
+                    match argument.as_number() {
+                        Some(number) => arguments.push(number as i64),
+                        None => return expected_parameter_type!("dilim".to_string(), "Sayı".to_string())
+                    };
+                }
+            },
+            0 | 1 => return n_parameter_expected!("dilim".to_string(), 2, parameter.length()),
+            _ => return n_parameter_expected!("dilim".to_string(), 3, parameter.length())
+        };
+
+        let step = arguments.get(2).copied().unwrap_or(1);
+        if step == 0 {
+            return Err(KaramelErrorType::StepCannotBeZero("dilim".to_string()));
+        }
+
+        /* Negative positions are counted from the end, like the indexer */
+        let list = list.borrow();
+        let length = list.len() as i64;
+        let position = |index: i64| match index < 0 {
+            true => index + length,
+            false => index
+        };
+
+        let mut items: Vec<VmObject> = Vec::new();
+        if step > 0 {
+            let mut index = position(arguments[0]).clamp(0, length);
+            let end = position(arguments[1]).clamp(0, length);
+            while index < end {
+                items.push(list[index as usize]);
+                index = match index.checked_add(step) {
+                    Some(index) => index,
+                    None => break
+                };
+            }
+        } else {
+            let mut index = position(arguments[0]).clamp(-1, length - 1);
+            let end = position(arguments[1]).clamp(-1, length - 1);
+            while index > end {
+                items.push(list[index as usize]);
+                index = match index.checked_add(step) {
+                    Some(index) => index,
+                    None => break
+                };
+            }
+        }
+        return Ok(VmObject::from(items));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+/* Calls the predicate with each element until the result of it is equal to 'expected' */
+fn find_predicate(parameter: &FunctionParameter, function_name: &str, expected: bool) -> Result<bool, KaramelErrorType> {
+    if parameter.length() != 1 {
+        return n_parameter_expected!(function_name.to_string(), 1, parameter.length());
+    }
+
+    let predicate = *parameter.iter().next().unwrap();
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        /* The predicate could change the list, so the elements are copied before the calls */
+        let items = list.borrow().clone();
+        for item in items.iter() {
+            if parameter.call(predicate, &[*item])?.deref().is_true() == expected {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn all(parameter: FunctionParameter) -> NativeCallResult {
+    let found = find_predicate(&parameter, "hepsi", false)?;
+    Ok(VmObject::from(!found))
+}
+
+fn any(parameter: FunctionParameter) -> NativeCallResult {
+    let found = find_predicate(&parameter, "herhangi", true)?;
+    Ok(VmObject::from(found))
+}
+
+fn map(parameter: FunctionParameter) -> NativeCallResult {
+    if parameter.length() != 1 {
+        return n_parameter_expected!("harita".to_string(), 1, parameter.length());
+    }
+
+    let function = *parameter.iter().next().unwrap();
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        /* The function could change the list, so the elements are copied before the calls */
+        let items = list.borrow().clone();
+        let mut results = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            results.push(parameter.call(function, &[*item])?);
+        }
+        return Ok(VmObject::from(results));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn filter(parameter: FunctionParameter) -> NativeCallResult {
+    if parameter.length() != 1 {
+        return n_parameter_expected!("filtrele".to_string(), 1, parameter.length());
+    }
+
+    let predicate = *parameter.iter().next().unwrap();
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let items = list.borrow().clone();
+        let mut results = Vec::new();
+        for item in items.iter() {
+            if parameter.call(predicate, &[*item])?.deref().is_true() {
+                results.push(*item);
+            }
+        }
+        return Ok(VmObject::from(results));
+    }
+    Ok(EMPTY_OBJECT)
+}
 
 #[cfg(test)]
 mod tests {
@@ -218,6 +489,7 @@ mod tests {
 
     use crate::nativecall_test_with_params;
     use crate::nativecall_test;
+    use crate::nativecall_error_test_with_params;
     use crate::primative_list;
     use crate::primative_text;
     use crate::arc_text;
@@ -232,6 +504,54 @@ mod tests {
     nativecall_test!{test_length_3, length,  primative_list!([arc_text!(""), arc_empty!(), arc_number!(123), arc_bool!(true)].to_vec()), KaramelPrimative::Number(4.0)}
 
 
+    nativecall_test!{test_unique_1, unique,  primative_list!([arc_number!(1), arc_number!(2), arc_number!(2), arc_number!(3), arc_number!(1)].to_vec()), primative_list!([arc_number!(1), arc_number!(2), arc_number!(3)].to_vec())}
+    nativecall_test!{test_unique_2, unique,  primative_list!([arc_text!("erhan"), arc_empty!(), arc_text!("erhan"), arc_empty!(), arc_bool!(true)].to_vec()), primative_list!([arc_text!("erhan"), arc_empty!(), arc_bool!(true)].to_vec())}
+    nativecall_test!{test_unique_3, unique,  primative_list!(Vec::new()), primative_list!(Vec::new())}
+
+    nativecall_test!{test_enumerate_1, enumerate,  primative_list!([arc_text!("a"), arc_text!("b")].to_vec()), primative_list!([VmObject::from([arc_number!(0), arc_text!("a")].to_vec()), VmObject::from([arc_number!(1), arc_text!("b")].to_vec())].to_vec())}
+    nativecall_test!{test_enumerate_2, enumerate,  primative_list!(Vec::new()), primative_list!(Vec::new())}
+    nativecall_test!{test_flatten_1, flatten,  primative_list!([VmObject::from([arc_number!(1), arc_number!(2)].to_vec()), VmObject::from([arc_number!(3)].to_vec()), VmObject::from([arc_number!(4), arc_number!(5)].to_vec())].to_vec()), primative_list!([arc_number!(1), arc_number!(2), arc_number!(3), arc_number!(4), arc_number!(5)].to_vec())}
+    nativecall_test!{test_flatten_2, flatten,  primative_list!([arc_number!(1), VmObject::from([arc_text!("erhan"), arc_empty!()].to_vec()), arc_bool!(true)].to_vec()), primative_list!([arc_number!(1), arc_text!("erhan"), arc_empty!(), arc_bool!(true)].to_vec())}
+    nativecall_test!{test_flatten_3, flatten,  primative_list!([VmObject::from([VmObject::from([arc_number!(1)].to_vec())].to_vec()), VmObject::from(Vec::new())].to_vec()), primative_list!([VmObject::from([arc_number!(1)].to_vec())].to_vec())}
+
+    nativecall_test_with_params!{test_count_1, count, primative_list!([arc_number!(1), arc_number!(2), arc_number!(2), arc_number!(3), arc_number!(2)].to_vec()), [arc_number!(2)], primative_number!(3)}
+    nativecall_test_with_params!{test_count_2, count, primative_list!([arc_text!("erhan"), arc_number!(2), arc_text!("erhan")].to_vec()), [arc_text!("erhan")], primative_number!(2)}
+    nativecall_test_with_params!{test_count_3, count, primative_list!([arc_number!(1), arc_number!(2)].to_vec()), [arc_text!("2")], primative_number!(0)}
+
+    nativecall_test!{test_min_1, min, primative_list!([arc_number!(3), arc_number!(-2), arc_number!(10)].to_vec()), primative_number!(-2)}
+    nativecall_test!{test_max_1, max, primative_list!([arc_number!(3), arc_number!(-2), arc_number!(10)].to_vec()), primative_number!(10)}
+    nativecall_test!{test_sum_1, sum, primative_list!([arc_number!(3), arc_number!(-2), arc_number!(10.5)].to_vec()), primative_number!(11.5)}
+    nativecall_test!{test_sum_2, sum, primative_list!(Vec::new()), primative_number!(0)}
+    nativecall_error_test_with_params!{test_min_2, min, primative_list!(Vec::new()), [], KaramelErrorType::ListIsEmpty("min".to_string())}
+    nativecall_error_test_with_params!{test_max_2, max, primative_list!(Vec::new()), [], KaramelErrorType::ListIsEmpty("maks".to_string())}
+    nativecall_error_test_with_params!{test_min_3, min, primative_list!([arc_number!(1), arc_text!("2")].to_vec()), [], KaramelErrorType::ListElementTypeNotValid { function: "min".to_string(), expected: "Sayı".to_string() }}
+    nativecall_error_test_with_params!{test_max_3, max, primative_list!([arc_number!(1), arc_empty!()].to_vec()), [], KaramelErrorType::ListElementTypeNotValid { function: "maks".to_string(), expected: "Sayı".to_string() }}
+    nativecall_error_test_with_params!{test_sum_3, sum, primative_list!([arc_bool!(true), arc_number!(1)].to_vec()), [], KaramelErrorType::ListElementTypeNotValid { function: "topla".to_string(), expected: "Sayı".to_string() }}
+
+    nativecall_test_with_params!{test_slice_1, slice, primative_list!([arc_number!(0), arc_number!(1), arc_number!(2), arc_number!(3), arc_number!(4), arc_number!(5)].to_vec()), [arc_number!(1), arc_number!(4)], primative_list!([arc_number!(1), arc_number!(2), arc_number!(3)].to_vec())}
+    nativecall_test_with_params!{test_slice_2, slice, primative_list!([arc_number!(0), arc_number!(1), arc_number!(2), arc_number!(3), arc_number!(4), arc_number!(5)].to_vec()), [arc_number!(0), arc_number!(6), arc_number!(2)], primative_list!([arc_number!(0), arc_number!(2), arc_number!(4)].to_vec())}
+    nativecall_test_with_params!{test_slice_3, slice, primative_list!([arc_number!(0), arc_number!(1), arc_number!(2), arc_number!(3), arc_number!(4), arc_number!(5)].to_vec()), [arc_number!(4), arc_number!(1), arc_number!(-1)], primative_list!([arc_number!(4), arc_number!(3), arc_number!(2)].to_vec())}
+    nativecall_test_with_params!{test_slice_4, slice, primative_list!([arc_number!(0), arc_number!(1), arc_number!(2), arc_number!(3), arc_number!(4), arc_number!(5)].to_vec()), [arc_number!(-1), arc_number!(0), arc_number!(-2)], primative_list!([arc_number!(5), arc_number!(3), arc_number!(1)].to_vec())}
+    nativecall_test_with_params!{test_slice_5, slice, primative_list!([arc_number!(0), arc_number!(1), arc_number!(2)].to_vec()), [arc_number!(-2), arc_number!(100)], primative_list!([arc_number!(1), arc_number!(2)].to_vec())}
+    nativecall_test_with_params!{test_slice_6, slice, primative_list!([arc_number!(0), arc_number!(1), arc_number!(2)].to_vec()), [arc_number!(2), arc_number!(1)], primative_list!(Vec::new())}
+    nativecall_error_test_with_params!{test_slice_7, slice, primative_list!([arc_number!(0), arc_number!(1)].to_vec()), [arc_number!(0), arc_number!(2), arc_number!(0)], KaramelErrorType::StepCannotBeZero("dilim".to_string())}
+    nativecall_error_test_with_params!{test_slice_8, slice, primative_list!([arc_number!(0), arc_number!(1)].to_vec()), [arc_number!(0), arc_text!("2")], KaramelErrorType::FunctionExpectedThatParameterType { function: "dilim".to_string(), expected: "Sayı".to_string() }}
+    nativecall_test_with_params!{test_slice_9, slice, primative_list!([arc_number!(0), arc_number!(1), arc_number!(2)].to_vec()), [arc_number!(1), arc_number!(3), arc_number!(9223372036854775808.0)], primative_list!([arc_number!(1)].to_vec())}
+    nativecall_test_with_params!{test_slice_10, slice, primative_list!([arc_number!(0), arc_number!(1), arc_number!(2)].to_vec()), [arc_number!(2), arc_number!(0), arc_number!(-9223372036854775808.0)], primative_list!([arc_number!(2)].to_vec())}
+
+    nativecall_error_test_with_params!{test_all_1, all, primative_list!([arc_number!(1)].to_vec()), [], KaramelErrorType::FunctionArgumentNotMatching { function: "hepsi".to_string(), expected: 1, found: 0 }}
+    nativecall_error_test_with_params!{test_map_1, map, primative_list!([arc_number!(1)].to_vec()), [], KaramelErrorType::FunctionArgumentNotMatching { function: "harita".to_string(), expected: 1, found: 0 }}
+    nativecall_error_test_with_params!{test_filter_1, filter, primative_list!([arc_number!(1)].to_vec()), [arc_number!(1), arc_number!(2)], KaramelErrorType::FunctionArgumentNotMatching { function: "filtrele".to_string(), expected: 1, found: 2 }}
+    nativecall_error_test_with_params!{test_any_1, any, primative_list!([arc_number!(1)].to_vec()), [arc_number!(1), arc_number!(2)], KaramelErrorType::FunctionArgumentNotMatching { function: "herhangi".to_string(), expected: 1, found: 2 }}
+
+    nativecall_test_with_params!{test_sort_1, sort, primative_list!([arc_number!(3), arc_number!(-1), arc_number!(2.5), arc_number!(0)].to_vec()), [], primative_list!([arc_number!(-1), arc_number!(0), arc_number!(2.5), arc_number!(3)].to_vec())}
+    nativecall_test_with_params!{test_sort_2, sort, primative_list!([arc_text!("kedi"), arc_text!("armut"), arc_text!("bal")].to_vec()), [], primative_list!([arc_text!("armut"), arc_text!("bal"), arc_text!("kedi")].to_vec())}
+    nativecall_test_with_params!{test_sort_3, sort, primative_list!([arc_number!(1), arc_number!(3), arc_number!(2)].to_vec()), [arc_bool!(true)], primative_list!([arc_number!(3), arc_number!(2), arc_number!(1)].to_vec())}
+    nativecall_test_with_params!{test_sort_4, sort, primative_list!([arc_text!("a"), arc_text!("c"), arc_text!("b")].to_vec()), [arc_bool!(false)], primative_list!([arc_text!("a"), arc_text!("b"), arc_text!("c")].to_vec())}
+    nativecall_test_with_params!{test_sort_5, sort, primative_list!(Vec::new()), [], primative_list!(Vec::new())}
+    nativecall_error_test_with_params!{test_sort_6, sort, primative_list!([arc_number!(1), arc_text!("a")].to_vec()), [], KaramelErrorType::MixedListCannotBeSorted("sırala".to_string())}
+    nativecall_error_test_with_params!{test_sort_7, sort, primative_list!([arc_bool!(true), arc_bool!(false)].to_vec()), [], KaramelErrorType::MixedListCannotBeSorted("sırala".to_string())}
+    nativecall_error_test_with_params!{test_sort_8, sort, primative_list!([arc_number!(1)].to_vec()), [arc_number!(1)], KaramelErrorType::FunctionExpectedThatParameterType { function: "sırala".to_string(), expected: "Bool".to_string() }}
     nativecall_test_with_params!{test_add_1, add, primative_list!([arc_text!("")].to_vec()), [VmObject::from(8.0)], primative_number!(1)}
     nativecall_test_with_params!{test_add_2, add, primative_list!(Vec::new()), [VmObject::native_convert(KaramelPrimative::Bool(true))], primative_number!(0)}
     #[test]
@@ -253,6 +573,27 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_pop_1 () {
+        use std::cell::RefCell;
+        let stdout = Some(RefCell::new(String::new()));
+        let stderr = Some(RefCell::new(String::new()));
+        let list = Rc::new(KaramelPrimative::List(RefCell::new([arc_text!("merhaba")].to_vec())));
+        let obj = VmObject::native_convert_by_ref(list.clone());
+
+        let result = pop(FunctionParameter::new(&Vec::new(), Some(obj), 0, 0, &stdout, &stderr));
+        assert_eq!(result.unwrap().deref(), Rc::new(primative_text!("merhaba")));
+
+        match &*list {
+            KaramelPrimative::List(l) => assert_eq!(l.borrow().len(), 0),
+            _ => assert_eq!(true, false)
+        };
+
+        /* Empty list returns boş */
+        let result = pop(FunctionParameter::new(&Vec::new(), Some(obj), 0, 0, &stdout, &stderr));
+        assert_eq!(result.unwrap().deref(), Rc::new(KaramelPrimative::Empty));
+    }
+
     #[test]
     fn test_insert_1 () {
         use std::cell::RefCell;
@@ -111,6 +111,23 @@ macro_rules! nativecall_test_with_params {
     };
 }
 
+#[macro_export]
+macro_rules! nativecall_error_test_with_params {
+    ($name:ident, $function_name:ident, $query:expr, $params:expr, $error:expr) => {
+        #[test]
+        fn $name () {
+            use std::cell::RefCell;
+            let stack: Vec<VmObject> = $params.to_vec();
+            let stdout = Some(RefCell::new(String::new()));
+            let stderr = Some(RefCell::new(String::new()));
+            
+            let parameter = FunctionParameter::new(&stack, Some(VmObject::native_convert($query)), stack.len() as usize, stack.len() as u8, &stdout, &stderr);
+            let result = $function_name(parameter);
+            assert_eq!(result.err(), Some($error));
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! n_parameter_check {
     ($function_name:expr, $parameter_size:expr) => {
@@ -2,13 +2,12 @@ use crate::{buildin::Class, compiler::function::{FunctionParameter, NativeCallRe
 use crate::compiler::value::EMPTY_OBJECT;
 use crate::buildin::class::baseclass::BasicInnerClass;
 use crate::compiler::value::KaramelPrimative;
-use crate::types::VmObject;
+use crate::types::{VmObject, POINTER_MASK};
 use crate::{n_parameter_expected, expected_parameter_type, arc_text};
 use crate::primative_text;
 use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
 use crate::error::KaramelErrorType;
 
-use unicode_width::UnicodeWidthStr;
 use std::{cell::RefCell, rc::Rc};
 
 
@@ -21,13 +20,24 @@ pub fn get_primative_class() -> Rc<dyn Class> {
     opcode.add_class_method("harflerikucult", lowercase);
     opcode.add_class_method("harfleribüyült", uppercase);
     opcode.add_class_method("harfleribuyult", uppercase);
+    opcode.add_class_method("küçükharf", lowercase);
+    opcode.add_class_method("kucukharf", lowercase);
+    opcode.add_class_method("büyükharf", uppercase);
+    opcode.add_class_method("buyukharf", uppercase);
     opcode.add_class_method("içeriyormu", contains);
     opcode.add_class_method("iceriyormu", contains);
+    opcode.add_class_method("başlıyormu", starts_with);
+    opcode.add_class_method("basliyormu", starts_with);
+    opcode.add_class_method("bitiyormu", ends_with);
     opcode.add_class_method("satırlar", lines);
     opcode.add_class_method("satirlar", lines);
+    opcode.add_class_method("böl_satır", lines);
+    opcode.add_class_method("bol_satir", lines);
     opcode.add_class_method("parçala", split);
     opcode.add_class_method("parcala", split);
     opcode.add_class_method("ara", find);
+    opcode.add_class_method("bul", find_first);
+    opcode.add_class_method("sondanbul", find_last);
     opcode.add_class_method("değiştir", replace);
     opcode.add_class_method("degistir", replace);
     opcode.add_class_method("kırp", trim);
@@ -36,11 +46,13 @@ pub fn get_primative_class() -> Rc<dyn Class> {
     opcode.add_class_method("sonukirp", end_trim);
     opcode.add_class_method("başıkırp", start_trim);
     opcode.add_class_method("basikirp", start_trim);
+    opcode.add_class_method("tersine", reverse);
     opcode.add_class_method("parçagetir", substring);
     opcode.add_class_method("parcagetir", substring);
     opcode.add_class_method("sayı", number);
     opcode.add_class_method("sayi", number);
     opcode.add_class_method("levenshtein", levenshtein);
+    opcode.add_class_method("denk_mi", equal_ignore_case);
     opcode.set_getter(getter);
     opcode.set_setter(setter);
 
@@ -71,46 +83,46 @@ fn setter(source: VmObject, index: f64, item: VmObject) -> NativeCallResult {
         false =>  return Ok(EMPTY_OBJECT)
     };
 
-    if let KaramelPrimative::Text(text) = &*source.deref() {
-        return match text.chars().nth(index) {
-            Some(old_char) => {
-                match &*item.deref() {
-                    KaramelPrimative::Text(data) => {
-                        if data.chars().count() != 1 {
-                            return Ok(EMPTY_OBJECT);
-                        }
+    let new_string = match (&*source.deref(), &*item.deref()) {
+        (KaramelPrimative::Text(text), KaramelPrimative::Text(data)) => {
+            let old_char = match text.chars().nth(index) {
+                Some(old_char) => old_char,
+                None => return Ok(EMPTY_OBJECT)
+            };
 
-                        let new_char = data.chars().nth(0).unwrap();
-                        let mut real_index = 0;
-                        let mut real_total = 0;
+            if data.chars().count() != 1 {
+                return Ok(EMPTY_OBJECT);
+            }
 
-                        for (i, ch) in text.chars().enumerate() {
-                            if i < index{
-                                real_index += ch.len_utf8();
-                            }
-                            real_total += ch.len_utf8();
-                        }
-                        
-                        /* full text size + new char size - old char size */
-                        let mut new_string = String::with_capacity(real_total + data.len() - old_char.len_utf8());
-                        new_string.push_str(&text[0..real_index]);
-                        new_string.push(new_char);
-                        new_string.push_str(&text[real_index+old_char.len_utf8()..]);
-
-                        unsafe {
-                            /* Update text with new one */
-                            let text_ptr = text as *const Rc<String> as *mut Rc<String>;
-                            *Rc::make_mut(&mut *text_ptr) = new_string;
-                        }
+            let new_char = data.chars().nth(0).unwrap();
+            let mut real_index = 0;
+            let mut real_total = 0;
 
-                        Ok(EMPTY_OBJECT)
-                    },
-                    _ => Ok(EMPTY_OBJECT) //We cant use other types in text
+            for (i, ch) in text.chars().enumerate() {
+                if i < index{
+                    real_index += ch.len_utf8();
                 }
-            },
-            None => Ok(EMPTY_OBJECT)
-        };
+                real_total += ch.len_utf8();
+            }
+            
+            /* full text size + new char size - old char size */
+            let mut new_string = String::with_capacity(real_total + data.len() - old_char.len_utf8());
+            new_string.push_str(&text[0..real_index]);
+            new_string.push(new_char);
+            new_string.push_str(&text[real_index+old_char.len_utf8()..]);
+            new_string
+        },
+        _ => return Ok(EMPTY_OBJECT) //We cant use other types in text
+    };
+
+    unsafe {
+        /* Update text with new one */
+        let primative_ptr = (source.0 & POINTER_MASK) as *mut KaramelPrimative;
+        if let KaramelPrimative::Text(text) = &mut *primative_ptr {
+            *Rc::make_mut(text) = new_string;
+        }
     }
+
     Ok(EMPTY_OBJECT)
 }
 
@@ -149,54 +161,85 @@ fn number(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
-fn contains(parameter: FunctionParameter) -> NativeCallResult {
+fn text_predicate(parameter: FunctionParameter, function_name: &str, predicate: fn(&str, &str) -> bool) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
         return match parameter.length() {
-            0 =>  n_parameter_expected!("içeriyormu".to_string(), 1),
+            0 =>  n_parameter_expected!(function_name.to_string(), 1),
             1 => {
                 match &*parameter.iter().next().unwrap().deref() {
-                    KaramelPrimative::Text(search) =>  Ok(VmObject::from(text.contains(&search[..]))),
-                    _ => expected_parameter_type!("içeriyormu".to_string(), "Yazı".to_string())
+                    KaramelPrimative::Text(search) =>  Ok(VmObject::from(predicate(text, search))),
+                    _ => expected_parameter_type!(function_name.to_string(), "Yazı".to_string())
                 }
             },
-            _ => n_parameter_expected!("içeriyormu".to_string(), 1, parameter.length())
+            _ => n_parameter_expected!(function_name.to_string(), 1, parameter.length())
         };
     }
     Ok(EMPTY_OBJECT)
 }
 
+fn contains(parameter: FunctionParameter) -> NativeCallResult {
+    text_predicate(parameter, "içeriyormu", |text, search| text.contains(search))
+}
+
+fn starts_with(parameter: FunctionParameter) -> NativeCallResult {
+    text_predicate(parameter, "başlıyormu", |text, search| text.starts_with(search))
+}
+
+fn ends_with(parameter: FunctionParameter) -> NativeCallResult {
+    text_predicate(parameter, "bitiyormu", |text, search| text.ends_with(search))
+}
+
+/* Dotted and dotless i of Turkish, other letters are converted with the unicode rules */
+const TURKISH_CASES: [(char, char); 2] = [('i', 'İ'), ('ı', 'I')];
+
+fn turkish_lowercase(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match TURKISH_CASES.iter().find(|(_, upper)| *upper == ch) {
+            Some((lower, _)) => result.push(*lower),
+            None => result.extend(ch.to_lowercase())
+        };
+    }
+    result
+}
+
+fn turkish_uppercase(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match TURKISH_CASES.iter().find(|(lower, _)| *lower == ch) {
+            Some((_, upper)) => result.push(*upper),
+            None => result.extend(ch.to_uppercase())
+        };
+    }
+    result
+}
+
 fn lowercase(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
-        let text:String = text.chars()
-        .map(|x| match x { 
-            'I' => 'ı', 
-            'İ' => 'i', 
-            'Ü' => 'ü', 
-            'Ğ' => 'ğ', 
-            'Ş' => 'ş', 
-            'Ç' => 'ç', 
-            'Ö' => 'ö',
-            _ => x
-        }).collect();
-        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(text.to_lowercase()))));
+        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(turkish_lowercase(text)))));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn equal_ignore_case(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 =>  n_parameter_expected!("denk_mi".to_string(), 1),
+            1 => {
+                match &*parameter.iter().next().unwrap().deref() {
+                    KaramelPrimative::Text(other) =>  Ok(VmObject::from(turkish_lowercase(text) == turkish_lowercase(other))),
+                    _ => expected_parameter_type!("denk_mi".to_string(), "Yazı".to_string())
+                }
+            },
+            _ => n_parameter_expected!("denk_mi".to_string(), 1, parameter.length())
+        };
     }
     Ok(EMPTY_OBJECT)
 }
 
 fn uppercase(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
-        let text:String = text.chars()
-        .map(|x| match x { 
-            'ı' => 'I', 
-            'i' => 'İ', 
-            'ü' => 'Ü', 
-            'ğ' => 'Ğ', 
-            'ş' => 'Ş', 
-            'ç' => 'Ç', 
-            'ö' => 'Ö',
-            _ => x
-        }).collect();
-        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(text.to_uppercase()))));
+        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(turkish_uppercase(text)))));
     }
     Ok(EMPTY_OBJECT)
 }
@@ -221,12 +264,11 @@ fn split(parameter: FunctionParameter) -> NativeCallResult {
             1 => {
                 match &*parameter.iter().next().unwrap().deref() {
                     KaramelPrimative::Text(search) =>  {
-                        let splits = text.split(&**search).collect::<Vec<_>>();
-                        let mut lines = Vec::new();
-
-                        for line in splits.iter() {
-                            lines.push(VmObject::native_convert(KaramelPrimative::Text(Rc::new(line.to_string()))));
-                        }
+                        /* Empty separator splits the text into its characters */
+                        let lines = match search.is_empty() {
+                            true => text.chars().map(|item| VmObject::native_convert(KaramelPrimative::Text(Rc::new(item.to_string())))).collect(),
+                            false => text.split(&**search).map(|line| VmObject::native_convert(KaramelPrimative::Text(Rc::new(line.to_string())))).collect()
+                        };
                         return Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(lines))));
                     },
                     _ => expected_parameter_type!("parçala".to_string(), "Yazı".to_string())
@@ -238,27 +280,40 @@ fn split(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
-fn find(parameter: FunctionParameter) -> NativeCallResult {
+fn text_position(parameter: FunctionParameter, function_name: &str, search_function: fn(&str, &str) -> Option<usize>) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
         return match parameter.length() {
-            0 =>  n_parameter_expected!("parçala".to_string(), 1),
+            0 =>  n_parameter_expected!(function_name.to_string(), 1),
             1 => {
                 match &*parameter.iter().next().unwrap().deref() {
+                    /* Byte location is converted to character location */
                     KaramelPrimative::Text(search) =>  {
-                        match text.find(&**search) {
-                            Some(location) => Ok(VmObject::native_convert(KaramelPrimative::Number(UnicodeWidthStr::width(&text[..location]) as f64))),
-                            _ => Ok(EMPTY_OBJECT)
+                        match search_function(text, search) {
+                            Some(location) => Ok(VmObject::from(text[..location].chars().count())),
+                            _ => Ok(VmObject::from(-1.0))
                         }
                     },
-                    _ => expected_parameter_type!("parçala".to_string(), "Yazı".to_string())
+                    _ => expected_parameter_type!(function_name.to_string(), "Yazı".to_string())
                 }
             },
-            _ => n_parameter_expected!("parçala".to_string(), 1, parameter.length())
+            _ => n_parameter_expected!(function_name.to_string(), 1, parameter.length())
         };
     }
     Ok(EMPTY_OBJECT)
 }
 
+fn find(parameter: FunctionParameter) -> NativeCallResult {
+    text_position(parameter, "ara", |text, search| text.find(search))
+}
+
+fn find_first(parameter: FunctionParameter) -> NativeCallResult {
+    text_position(parameter, "bul", |text, search| text.find(search))
+}
+
+fn find_last(parameter: FunctionParameter) -> NativeCallResult {
+    text_position(parameter, "sondanbul", |text, search| text.rfind(search))
+}
+
 fn replace(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
         return match parameter.length() {
@@ -267,6 +322,8 @@ fn replace(parameter: FunctionParameter) -> NativeCallResult {
                 let mut iter = parameter.iter();
                 let (from, to) = (&*iter.next().unwrap().deref(), &*iter.next().unwrap().deref());
                 match (&*from, &*to) {
+                    /* Empty search would put the replacement between all characters */
+                    (KaramelPrimative::Text(from), KaramelPrimative::Text(_)) if from.is_empty() => Ok(VmObject::native_convert(KaramelPrimative::Text(text.clone()))),
                     (KaramelPrimative::Text(from), KaramelPrimative::Text(to)) => Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(text.replace(&**from, &**to))))),
                     _ => expected_parameter_type!("değiştir".to_string(), "Yazı".to_string())
                 }
@@ -284,6 +341,16 @@ fn trim(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+fn reverse(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
+        return match parameter.length() {
+            0 => Ok(VmObject::native_convert(primative_text!(text.chars().rev().collect::<String>()))),
+            _ => n_parameter_expected!("tersine".to_string(), 0, parameter.length())
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
 fn end_trim(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*parameter.source().unwrap().deref() {
         return Ok(VmObject::native_convert(primative_text!(text.trim_end())));
@@ -337,6 +404,7 @@ mod tests {
 
     use crate::nativecall_test;
     use crate::nativecall_test_with_params;
+    use crate::nativecall_error_test_with_params;
     use crate::primative_text;
 
 
@@ -349,33 +417,80 @@ mod tests {
     nativecall_test!{test_uppercase_1, uppercase, KaramelPrimative::Text(Rc::new("türkiye".to_string())), KaramelPrimative::Text(Rc::new("TÜRKİYE".to_string()))}
     nativecall_test!{test_uppercase_2, uppercase, KaramelPrimative::Text(Rc::new("ığüişçö".to_string())), KaramelPrimative::Text(Rc::new("IĞÜİŞÇÖ".to_string()))}
     nativecall_test!{test_uppercase_3, uppercase, KaramelPrimative::Text(Rc::new("erhan".to_string())), KaramelPrimative::Text(Rc::new("ERHAN".to_string()))}
+    nativecall_test!{test_uppercase_4, uppercase, KaramelPrimative::Text(Rc::new("istanbul".to_string())), KaramelPrimative::Text(Rc::new("İSTANBUL".to_string()))}
+    nativecall_test!{test_uppercase_5, uppercase, KaramelPrimative::Text(Rc::new("ıi Straße 1".to_string())), KaramelPrimative::Text(Rc::new("Iİ STRASSE 1".to_string()))}
+    nativecall_test!{test_lowercase_4, lowercase, KaramelPrimative::Text(Rc::new("IĞDIR".to_string())), KaramelPrimative::Text(Rc::new("ığdır".to_string()))}
+    nativecall_test!{test_lowercase_5, lowercase, KaramelPrimative::Text(Rc::new("Hello WORLD 1".to_string())), KaramelPrimative::Text(Rc::new("hello world 1".to_string()))}
     nativecall_test!{test_lines_1, lines, KaramelPrimative::Text(Rc::new("erhan\r\n".to_string())), KaramelPrimative::List(RefCell::new([VmObject::native_convert(KaramelPrimative::Text(Rc::new("erhan".to_string())))].to_vec()))}
     nativecall_test!{test_lines_2, lines, KaramelPrimative::Text(Rc::new("\r\n".to_string())), KaramelPrimative::List(RefCell::new([VmObject::native_convert(KaramelPrimative::Text(Rc::new("".to_string())))].to_vec()))}
     nativecall_test!{test_lines_3, lines, KaramelPrimative::Text(Rc::new("erhan\r\nbarış".to_string())), KaramelPrimative::List(RefCell::new([VmObject::native_convert(KaramelPrimative::Text(Rc::new("erhan".to_string()))), VmObject::native_convert(KaramelPrimative::Text(Rc::new("barış".to_string())))].to_vec()))}
     nativecall_test!{test_lines_4, lines, KaramelPrimative::Text(Rc::new("erhan\r\nbarış\r\n".to_string())), KaramelPrimative::List(RefCell::new([VmObject::native_convert(KaramelPrimative::Text(Rc::new("erhan".to_string()))), VmObject::native_convert(KaramelPrimative::Text(Rc::new("barış".to_string())))].to_vec()))}
     nativecall_test!{test_lines_5, lines, KaramelPrimative::Text(Rc::new("erhan\r\nbarış\r\nkaramel".to_string())), KaramelPrimative::List(RefCell::new([VmObject::native_convert(KaramelPrimative::Text(Rc::new("erhan".to_string()))), VmObject::native_convert(KaramelPrimative::Text(Rc::new("barış".to_string()))), VmObject::native_convert(KaramelPrimative::Text(Rc::new("karamel".to_string())))].to_vec()))}
+    nativecall_test!{test_lines_6, lines, KaramelPrimative::Text(Rc::new("".to_string())), KaramelPrimative::List(RefCell::new(Vec::new()))}
+    nativecall_test!{test_lines_7, lines, KaramelPrimative::Text(Rc::new("erhan\nbarış\r\nkaramel\n".to_string())), KaramelPrimative::List(RefCell::new([VmObject::native_convert(KaramelPrimative::Text(Rc::new("erhan".to_string()))), VmObject::native_convert(KaramelPrimative::Text(Rc::new("barış".to_string()))), VmObject::native_convert(KaramelPrimative::Text(Rc::new("karamel".to_string())))].to_vec()))}
     
     nativecall_test_with_params!{test_split_1, split, primative_text!("erhan\r\n"), [VmObject::native_convert(primative_text!("erhan"))], KaramelPrimative::List(RefCell::new([VmObject::native_convert(primative_text!("")), VmObject::native_convert(primative_text!("\r\n"))].to_vec()))}
     nativecall_test_with_params!{test_split_2, split, primative_text!("erhanbarışerhan"), [VmObject::native_convert(primative_text!("barış"))], KaramelPrimative::List(RefCell::new([VmObject::native_convert(primative_text!("erhan")), VmObject::native_convert(primative_text!("erhan"))].to_vec()))}
     nativecall_test_with_params!{test_split_3, split, primative_text!("karamel"), [VmObject::native_convert(primative_text!("erhan"))], KaramelPrimative::List(RefCell::new([VmObject::native_convert(primative_text!("karamel"))].to_vec()))}
+    nativecall_test_with_params!{test_split_4, split, primative_text!("a,b,c"), [VmObject::native_convert(primative_text!(","))], KaramelPrimative::List(RefCell::new([VmObject::native_convert(primative_text!("a")), VmObject::native_convert(primative_text!("b")), VmObject::native_convert(primative_text!("c"))].to_vec()))}
+    nativecall_test_with_params!{test_split_5, split, primative_text!("çay"), [VmObject::native_convert(primative_text!(""))], KaramelPrimative::List(RefCell::new([VmObject::native_convert(primative_text!("ç")), VmObject::native_convert(primative_text!("a")), VmObject::native_convert(primative_text!("y"))].to_vec()))}
+    nativecall_test_with_params!{test_split_6, split, primative_text!(""), [VmObject::native_convert(primative_text!(""))], KaramelPrimative::List(RefCell::new(Vec::new()))}
 
     nativecall_test_with_params!{test_contains_1, contains, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("erhan"))], KaramelPrimative::Bool(false)}
     nativecall_test_with_params!{test_contains_2, contains, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("merhaba"))], KaramelPrimative::Bool(true)}
     nativecall_test_with_params!{test_contains_3, contains, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("dünya"))], KaramelPrimative::Bool(true)}
     nativecall_test_with_params!{test_contains_4, contains, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!(" "))], KaramelPrimative::Bool(true)}
     nativecall_test_with_params!{test_contains_5, contains, primative_text!("bir karamel miyav dedi minik fare kükredi"), [VmObject::native_convert(primative_text!("minik fare"))], KaramelPrimative::Bool(true)}
-
-    nativecall_test_with_params!{test_find_1, find, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("erhan"))], KaramelPrimative::Empty}
+    nativecall_test_with_params!{test_contains_6, contains, primative_text!("karamel"), [VmObject::native_convert(primative_text!(""))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_contains_7, contains, primative_text!("Karamel"), [VmObject::native_convert(primative_text!("karamel"))], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_starts_with_1, starts_with, primative_text!("karamel"), [VmObject::native_convert(primative_text!("kara"))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_starts_with_2, starts_with, primative_text!("karamel"), [VmObject::native_convert(primative_text!("mel"))], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_starts_with_3, starts_with, primative_text!("karamel"), [VmObject::native_convert(primative_text!(""))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_starts_with_4, starts_with, primative_text!("İstanbul"), [VmObject::native_convert(primative_text!("istanbul"))], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_ends_with_1, ends_with, primative_text!("karamel"), [VmObject::native_convert(primative_text!("mel"))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_ends_with_2, ends_with, primative_text!("karamel"), [VmObject::native_convert(primative_text!("kara"))], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_ends_with_3, ends_with, primative_text!("karamel"), [VmObject::native_convert(primative_text!(""))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_ends_with_4, ends_with, primative_text!("ışık"), [VmObject::native_convert(primative_text!("IŞIK"))], KaramelPrimative::Bool(false)}
+
+    nativecall_test_with_params!{test_equal_ignore_case_1, equal_ignore_case, primative_text!("İstanbul"), [VmObject::native_convert(primative_text!("istanbul"))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_equal_ignore_case_2, equal_ignore_case, primative_text!("ISPARTA"), [VmObject::native_convert(primative_text!("ısparta"))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_equal_ignore_case_3, equal_ignore_case, primative_text!("Istanbul"), [VmObject::native_convert(primative_text!("istanbul"))], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_equal_ignore_case_4, equal_ignore_case, primative_text!("ISPARTA"), [VmObject::native_convert(primative_text!("isparta"))], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_equal_ignore_case_5, equal_ignore_case, primative_text!("ĞÜŞÖÇ"), [VmObject::native_convert(primative_text!("ğüşöç"))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_equal_ignore_case_6, equal_ignore_case, primative_text!("karamel"), [VmObject::native_convert(primative_text!("karamell"))], KaramelPrimative::Bool(false)}
+
+    nativecall_test_with_params!{test_find_1, find, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("erhan"))], KaramelPrimative::Number(-1.0)}
     nativecall_test_with_params!{test_find_2, find, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("merhaba"))], KaramelPrimative::Number(0.0)}
     nativecall_test_with_params!{test_find_3, find, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("dünya"))], KaramelPrimative::Number(8.0)}
     nativecall_test_with_params!{test_find_4, find, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!(" "))], KaramelPrimative::Number(7.0)}
     nativecall_test_with_params!{test_find_5, find, primative_text!("bir karamel miyav dedi minik fare kükredi"), [VmObject::native_convert(primative_text!("minik fare"))], KaramelPrimative::Number(23.0)}
     nativecall_test_with_params!{test_find_6, find, primative_text!("kütüphaneciler haftası"), [VmObject::native_convert(primative_text!("hafta"))], KaramelPrimative::Number(15.0)}
     nativecall_test_with_params!{test_find_7, find, primative_text!("şaşkın şakir Gündüz"), [VmObject::native_convert(primative_text!("Gündüz"))], KaramelPrimative::Number(13.0)}
+    nativecall_test_with_params!{test_find_8, find, primative_text!("ığüşçö karamel"), [VmObject::native_convert(primative_text!("karamel"))], KaramelPrimative::Number(7.0)}
+    nativecall_test_with_params!{test_find_9, find, primative_text!("ığüşçö karamel"), [VmObject::native_convert(primative_text!(""))], KaramelPrimative::Number(0.0)}
+    nativecall_test_with_params!{test_find_10, find, primative_text!("ığüşçö karamel"), [VmObject::native_convert(primative_text!("erhan"))], KaramelPrimative::Number(-1.0)}
+    nativecall_test_with_params!{test_find_11, find, primative_text!("çayçay"), [VmObject::native_convert(primative_text!("y"))], KaramelPrimative::Number(2.0)}
+    nativecall_error_test_with_params!{test_find_12, find, primative_text!("çayçay"), [VmObject::native_convert(KaramelPrimative::Number(1.0))], KaramelErrorType::FunctionExpectedThatParameterType { function: "ara".to_string(), expected: "Yazı".to_string() }}
+    nativecall_test_with_params!{test_find_13, find_first, primative_text!("çayçay"), [VmObject::native_convert(primative_text!("y"))], KaramelPrimative::Number(2.0)}
+    nativecall_error_test_with_params!{test_find_14, find_first, primative_text!("çay"), [VmObject::native_convert(KaramelPrimative::Number(1.0))], KaramelErrorType::FunctionExpectedThatParameterType { function: "bul".to_string(), expected: "Yazı".to_string() }}
+
+    nativecall_test_with_params!{test_find_last_1, find_last, primative_text!("çayçay"), [VmObject::native_convert(primative_text!("y"))], KaramelPrimative::Number(5.0)}
+    nativecall_test_with_params!{test_find_last_2, find_last, primative_text!("çayçay"), [VmObject::native_convert(primative_text!("ça"))], KaramelPrimative::Number(3.0)}
+    nativecall_test_with_params!{test_find_last_3, find_last, primative_text!("çayçay"), [VmObject::native_convert(primative_text!("erhan"))], KaramelPrimative::Number(-1.0)}
+    nativecall_test_with_params!{test_find_last_4, find_last, primative_text!("çayçay"), [VmObject::native_convert(primative_text!(""))], KaramelPrimative::Number(6.0)}
+    nativecall_test_with_params!{test_find_last_5, find_last, primative_text!("şaşkın şakir"), [VmObject::native_convert(primative_text!("şa"))], KaramelPrimative::Number(7.0)}
 
     nativecall_test_with_params!{test_replace_1, replace, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("dünya")), VmObject::native_convert(primative_text!("erhan"))], primative_text!("merhaba erhan")}
     nativecall_test_with_params!{test_replace_2, replace, primative_text!("merhaba dünya"), [VmObject::native_convert(primative_text!("test")), VmObject::native_convert(primative_text!("erhan"))], primative_text!("merhaba dünya")}
+    nativecall_test_with_params!{test_replace_3, replace, primative_text!("aaa"), [VmObject::native_convert(primative_text!("a")), VmObject::native_convert(primative_text!("b"))], primative_text!("bbb")}
+    nativecall_test_with_params!{test_replace_4, replace, primative_text!("karamel"), [VmObject::native_convert(primative_text!("a")), VmObject::native_convert(primative_text!(""))], primative_text!("krmel")}
+    nativecall_test_with_params!{test_replace_5, replace, primative_text!("karamel"), [VmObject::native_convert(primative_text!("")), VmObject::native_convert(primative_text!("-"))], primative_text!("karamel")}
+    nativecall_error_test_with_params!{test_replace_6, replace, primative_text!("karamel"), [VmObject::native_convert(primative_text!("a"))], KaramelErrorType::FunctionArgumentNotMatching { function: "değiştir".to_string(), expected: 2, found: 1 }}
     
+    nativecall_test!{test_reverse_1, reverse, primative_text!("abc"), primative_text!("cba")}
+    nativecall_test!{test_reverse_2, reverse, primative_text!(""), primative_text!("")}
+    nativecall_test!{test_reverse_3, reverse, primative_text!("çığöşü"), primative_text!("üşöğıç")}
+    nativecall_error_test_with_params!{test_reverse_4, reverse, primative_text!("abc"), [VmObject::native_convert(primative_text!("a"))], KaramelErrorType::FunctionArgumentNotMatching { function: "tersine".to_string(), expected: 0, found: 1 }}
     nativecall_test!{test_trim_1, trim, primative_text!(" merhaba dünya "), primative_text!("merhaba dünya")}
     nativecall_test!{test_trim_2, trim, primative_text!("merhaba dünya "), primative_text!("merhaba dünya")}
     nativecall_test!{test_trim_3, trim, primative_text!(" merhaba dünya"), primative_text!("merhaba dünya")}
@@ -1,6 +1,7 @@
 use crate::compiler::{function::{FunctionParameter, FunctionReference, NativeCall, NativeCallResult}};
 use crate::types::{VmObject};
 use crate::compiler::value::EMPTY_OBJECT;
+use crate::compiler::value::KaramelPrimative;
 use crate::buildin::{Module, Class};
 use std::{cell::RefCell, collections::HashMap};
 use std::rc::Rc;
@@ -73,11 +74,19 @@ impl IoModule  {
         }
     }
 
-    pub fn print(parameter: FunctionParameter) -> NativeCallResult {
-        let mut buffer = String::new();
+    /* Texts are written as they are, other values are written with their display format */
+    fn write_arguments(parameter: &FunctionParameter, buffer: &mut String) {
         for arg in parameter.iter() {
-            buffer.push_str(&format!("{}", arg.deref()));
+            match &*arg.deref() {
+                KaramelPrimative::Text(text) => buffer.push_str(text),
+                primative => buffer.push_str(&format!("{}", primative))
+            };
         }
+    }
+
+    pub fn print(parameter: FunctionParameter) -> NativeCallResult {
+        let mut buffer = String::new();
+        IoModule::write_arguments(&parameter, &mut buffer);
         log::info!("{}", buffer);
                 
         parameter.write_to_stdout(&buffer);
@@ -86,11 +95,7 @@ impl IoModule  {
     
     pub fn printline(parameter: FunctionParameter) -> NativeCallResult {
         let mut buffer = String::new();
-
-        for arg in parameter.iter() {
-            buffer.push_str(&format!("{}", arg.deref()));
-        }
-
+        IoModule::write_arguments(&parameter, &mut buffer);
         buffer.push_str(&"\r\n");
         log::info!("{}", buffer);
 
@@ -2,6 +2,8 @@ pub mod debug;
 pub mod io;
 pub mod num;
 pub mod base_functions;
+#[cfg(feature = "patternMatching")]
+pub mod pattern;
 
 use std::collections::hash_map::Iter;
 
@@ -27,6 +29,15 @@ pub trait Module {
     fn get_modules(&self) -> HashMap<String, Rc<dyn Module>>;
 
     fn get_classes(&self) -> Vec<Rc<dyn Class>>;
+
+    /// Module level values, `modül::sabit`.
+    fn get_constant(&self, _: &str) -> Option<Rc<KaramelPrimative>> {
+        None
+    }
+
+    fn get_constants(&self) -> HashMap<String, Rc<KaramelPrimative>> {
+        HashMap::new()
+    }
 }
 
 pub struct ModuleCollectionIterator<'a> {
@@ -0,0 +1,150 @@
+use crate::compiler::{function::{FunctionParameter, FunctionReference, NativeCall, NativeCallResult}};
+use crate::types::VmObject;
+use crate::compiler::value::KaramelPrimative;
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::error::KaramelErrorType;
+use crate::buildin::{Module, Class};
+use crate::{n_parameter_expected, expected_parameter_type};
+use std::{cell::RefCell, collections::HashMap};
+use std::rc::Rc;
+
+use regex::Regex;
+
+#[derive(Clone)]
+pub struct PatternModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>
+}
+
+impl Module for PatternModule {
+    fn get_module_name(&self) -> String {
+        "desen".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods.borrow().iter().for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+}
+
+impl PatternModule {
+    pub fn new() -> Rc<PatternModule> {
+        let module = PatternModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["desen".to_string()]
+        };
+
+        let rc_module = Rc::new(module);
+        rc_module.methods.borrow_mut().insert("eşleş".to_string(), FunctionReference::native_function(is_match as NativeCall, "eşleş".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("esles".to_string(), FunctionReference::native_function(is_match as NativeCall, "esles".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("bul".to_string(), FunctionReference::native_function(find_all as NativeCall, "bul".to_string(), rc_module.clone()));
+        rc_module.clone()
+    }
+}
+
+/// Reads (text, pattern) parameters and compiles the pattern.
+fn text_and_pattern(parameter: &FunctionParameter, function_name: &str) -> Result<(Rc<String>, Regex), KaramelErrorType> {
+    if parameter.length() != 2 {
+        return n_parameter_expected!(function_name.to_string(), 2, parameter.length());
+    }
+
+    let mut iter = parameter.iter();
+    let (text, pattern) = (iter.next().unwrap().deref(), iter.next().unwrap().deref());
+    match (&*text, &*pattern) {
+        (KaramelPrimative::Text(text), KaramelPrimative::Text(pattern)) => match Regex::new(pattern) {
+            Ok(regex) => Ok((text.clone(), regex)),
+            Err(error) => Err(KaramelErrorType::PatternNotValid {
+                pattern: pattern.to_string(),
+                error: error.to_string()
+            })
+        },
+        _ => expected_parameter_type!(function_name.to_string(), "Yazı".to_string())
+    }
+}
+
+fn is_match(parameter: FunctionParameter) -> NativeCallResult {
+    let (text, regex) = text_and_pattern(&parameter, "eşleş")?;
+    Ok(VmObject::from(regex.is_match(&text)))
+}
+
+/// Returns every match as a list. If the pattern has capture groups, each
+/// item is a list of the captured groups instead of the whole match.
+fn find_all(parameter: FunctionParameter) -> NativeCallResult {
+    let (text, regex) = text_and_pattern(&parameter, "bul")?;
+    let mut matches = Vec::new();
+
+    match regex.captures_len() {
+        1 => {
+            for item in regex.find_iter(&text) {
+                matches.push(VmObject::from(item.as_str().to_string()));
+            }
+        },
+        _ => {
+            for captures in regex.captures_iter(&text) {
+                let groups = captures.iter().skip(1).map(|group| match group {
+                    Some(group) => VmObject::from(group.as_str().to_string()),
+                    None => EMPTY_OBJECT
+                }).collect::<Vec<_>>();
+                matches.push(VmObject::from(groups));
+            }
+        }
+    };
+
+    Ok(VmObject::from(matches))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use crate::compiler::value::KaramelPrimative;
+    use super::*;
+
+    use crate::nativecall_test_with_params;
+    use crate::primative_text;
+
+    nativecall_test_with_params!{test_is_match_1, is_match, KaramelPrimative::Empty, [VmObject::native_convert(primative_text!("karamel 2021")), VmObject::native_convert(primative_text!("[0-9]+"))], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_is_match_2, is_match, KaramelPrimative::Empty, [VmObject::native_convert(primative_text!("karamel")), VmObject::native_convert(primative_text!("^[0-9]+$"))], KaramelPrimative::Bool(false)}
+
+    nativecall_test_with_params!{test_find_all_1, find_all, KaramelPrimative::Empty, [VmObject::native_convert(primative_text!("1 elma, 22 armut")), VmObject::native_convert(primative_text!("[0-9]+"))], KaramelPrimative::List(RefCell::new([VmObject::native_convert(primative_text!("1")), VmObject::native_convert(primative_text!("22"))].to_vec()))}
+    nativecall_test_with_params!{test_find_all_2, find_all, KaramelPrimative::Empty, [VmObject::native_convert(primative_text!("ad=erhan, soyad=barış")), VmObject::native_convert(primative_text!("(\\w+)=(\\w+)"))], KaramelPrimative::List(RefCell::new([
+        VmObject::native_convert(KaramelPrimative::List(RefCell::new([VmObject::native_convert(primative_text!("ad")), VmObject::native_convert(primative_text!("erhan"))].to_vec()))),
+        VmObject::native_convert(KaramelPrimative::List(RefCell::new([VmObject::native_convert(primative_text!("soyad")), VmObject::native_convert(primative_text!("barış"))].to_vec())))].to_vec()))}
+    nativecall_test_with_params!{test_find_all_3, find_all, KaramelPrimative::Empty, [VmObject::native_convert(primative_text!("karamel")), VmObject::native_convert(primative_text!("[0-9]+"))], KaramelPrimative::List(RefCell::new(Vec::new()))}
+
+    #[test]
+    fn test_invalid_pattern() {
+        let stack: Vec<VmObject> = [VmObject::native_convert(primative_text!("karamel")), VmObject::native_convert(primative_text!("[0-9"))].to_vec();
+        let stdout = Some(RefCell::new(String::new()));
+        let stderr = Some(RefCell::new(String::new()));
+
+        let parameter = FunctionParameter::new(&stack, None, stack.len(), stack.len() as u8, &stdout, &stderr);
+        match is_match(parameter) {
+            Err(KaramelErrorType::PatternNotValid { pattern, error }) => {
+                assert_eq!(pattern, "[0-9");
+                assert!(error.len() > 0);
+            },
+            _ => assert!(false, "Hatalı desen kabul edildi")
+        };
+    }
+}
@@ -20,10 +20,33 @@ pub struct KaramelIfStatementElseItem {
 #[derive(Debug)]
 #[derive(PartialEq)]
 pub struct KaramelDictItem {
-    pub key: Rc<KaramelPrimative>,
+    pub key: Rc<KaramelAstType>,
     pub value: Rc<KaramelAstType>
 }
 
+/// Line and column of the token a node starts with. Positions are not
+/// compared, so trees parsed from differently spaced code are still equal.
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct KaramelAstPosition {
+    pub line: u32,
+    pub column: u32
+}
+
+impl KaramelAstPosition {
+    pub fn new(line: u32, column: u32) -> KaramelAstPosition {
+        KaramelAstPosition { line, column }
+    }
+}
+
+impl PartialEq for KaramelAstPosition {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 impl KaramelIfStatementElseItem {
     pub fn new(condition: Rc<KaramelAstType>, body: Rc<KaramelAstType>) -> KaramelIfStatementElseItem {
         KaramelIfStatementElseItem {
@@ -44,13 +67,16 @@ pub enum KaramelAstType {
     FuncCall {
         func_name_expression: Rc<KaramelAstType>,
         arguments: Vec<Rc<KaramelAstType>>,
-        assign_to_temp: Cell<bool>
+        assign_to_temp: Cell<bool>,
+        position: KaramelAstPosition
     },
     AccessorFuncCall {
         source: Rc<KaramelAstType>,
         indexer: Rc<KaramelAstType>,
         assign_to_temp: Cell<bool>
     },
+    /// List argument of a function call, items of it are passed as positional arguments. `topla(*liste)`
+    Spread(Rc<KaramelAstType>),
     Primative(Rc<KaramelPrimative>),
     Binary {
         left: Rc<KaramelAstType>, 
@@ -72,7 +98,8 @@ pub enum KaramelAstType {
     Assignment {
         variable: Rc<KaramelAstType>,
         operator: KaramelOperatorType,
-        expression: Rc<KaramelAstType>
+        expression: Rc<KaramelAstType>,
+        position: KaramelAstPosition
     },
     IfStatement {
         condition: Rc<KaramelAstType>,
@@ -83,7 +110,10 @@ pub enum KaramelAstType {
     FunctionDefination {
         name: String,
         arguments: Vec<String>,
-        body: Rc<KaramelAstType>
+        /// Positions of the argument names, in the same order.
+        argument_positions: Vec<KaramelAstPosition>,
+        body: Rc<KaramelAstType>,
+        position: KaramelAstPosition
     },
     Symbol(String),
     ModulePath(Vec<String>),
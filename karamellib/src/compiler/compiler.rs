@@ -57,16 +57,61 @@ impl InterpreterCompiler {
         context.opcode_generator.set_current_location(main_location.clone());
 
         /* Generate main function code */
-        self.generate_opcode(main_module.clone(), &*main_ast, &KaramelAstType::None, context, 0)?;
+        self.generate_main(main_module.clone(), &*main_ast, context)?;
         context.opcode_generator.add_opcode(VmOpCode::Halt);
         context.opcode_generator.generate(&mut context.opcodes);
+        context.method_caches = Vec::new();
 
         context.opcodes_ptr     = context.opcodes.as_mut_ptr();
         context.opcodes_top_ptr = context.opcodes_ptr;
 
+        self.find_unused_variables(&functions, context);
+
+        if context.config.shadowing_warnings {
+            self.find_shadowed_variables(&functions, context);
+        }
         Ok(())
     }
 
+    fn find_unused_variables(&self, functions: &[Rc<FunctionReference>], context: &mut KaramelCompilerContext) {
+        for storage in context.storages.iter() {
+            /* Function arguments are not reported */
+            let arguments = functions.iter()
+                .filter(|function| function.storage_index == storage.index)
+                .flat_map(|function| function.arguments.iter())
+                .collect::<Vec<_>>();
+
+            for (variable, (used, position)) in storage.variables.iter().zip(storage.used_variables.iter().zip(storage.variable_positions.iter())) {
+                /* Only assigned variables are reported, at their first assignment */
+                if let (false, Some(position)) = (*used, position) {
+                    if !arguments.contains(&variable) {
+                        context.diagnostics.push(KaramelDiagnostic::warning(KaramelDiagnosticType::UnusedVariable(variable.to_string()), position.line, position.column));
+                    }
+                }
+            }
+        }
+    }
+
+    fn find_shadowed_variables(&self, functions: &[Rc<FunctionReference>], context: &mut KaramelCompilerContext) {
+        for function in functions.iter() {
+            let storage = &context.storages[function.storage_index];
+
+            for (index, variable) in storage.variables.iter().enumerate() {
+                let position = storage.get_variable_position(index as u8).unwrap_or_default();
+                let mut parent_location = storage.get_parent_location();
+
+                while let Some(location) = parent_location {
+                    let parent = &context.storages[location];
+                    if parent.get_variable_location(variable).is_some() {
+                        context.diagnostics.push(KaramelDiagnostic::warning(KaramelDiagnosticType::ShadowedVariable(variable.to_string(), function.name.to_string()), position.line, position.column));
+                        break;
+                    }
+                    parent_location = parent.get_parent_location();
+                }
+            }
+        }
+    }
+
     pub fn detect_modules(&self, main_ast: Rc<KaramelAstType>, context: &mut KaramelCompilerContext) -> Result<Vec<Rc<OpcodeModule>>, KaramelErrorType> {
         Ok(get_modules(main_ast.clone(), context)?)
     }
@@ -82,7 +127,7 @@ impl InterpreterCompiler {
     }
     
     pub fn check_prohibited_names<T: Borrow<String>>(&self, variable: T) -> Result<(), KaramelErrorType> {
-        if KEYWORDS.iter().any(|(key, _)| variable.borrow() == *key) {
+        if KEYWORD_MAP.contains_key(variable.borrow().as_str()) {
             return Err(KaramelErrorType::ReservedName(variable.borrow().to_string()));
         }
 
@@ -110,7 +155,7 @@ impl InterpreterCompiler {
 
     fn get_function_definations(&self, module: Rc<OpcodeModule>, ast: Rc<KaramelAstType>, functions: &mut Vec<Rc<FunctionReference>>, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult{
         match &*ast {
-            KaramelAstType::FunctionDefination { name, arguments: _, body  } => {
+            KaramelAstType::FunctionDefination { name, arguments: _, argument_positions: _, body, position: _ } => {
                 let search = context.get_function(name.to_string(), module.get_path(), storage_index);
                 match search {
                     Some(reference) => {
@@ -141,8 +186,12 @@ impl InterpreterCompiler {
             }
 
             self.check_prohibited_names(&function.name)?;
+            let function_start = context.opcode_generator.len();
             context.opcode_generator.create_function_definition(function.clone());
+            context.opcode_generator.function_started();
             self.generate_opcode(module.clone(), &function.opcode_body.as_ref().unwrap(), &function.opcode_body.as_ref().unwrap(), context, function.storage_index as usize)?;
+            context.opcode_generator.function_finished();
+            function.max_stack_depth.set(context.opcode_generator.max_stack_depth(function_start));
         }
 
         Ok(())
@@ -150,7 +199,7 @@ impl InterpreterCompiler {
 
     fn generate_opcode(&self, module: Rc<OpcodeModule>, ast: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         match ast {
-            KaramelAstType::Assignment { variable, operator, expression } => self.generate_assignment(module.clone(), variable, operator, expression, context, storage_index),
+            KaramelAstType::Assignment { variable, operator, expression, position: _ } => self.generate_assignment(module.clone(), variable, operator, expression, context, storage_index),
             KaramelAstType::Symbol(variable) => self.generate_symbol(module.clone(), variable, upper_ast, context, storage_index),
             KaramelAstType::Control { left, operator, right } => self.generate_control(module.clone(), left, operator, right, upper_ast, context, storage_index),
             KaramelAstType::Binary { left, operator, right } => self.generate_binary(module.clone(), left, operator, right, upper_ast, context, storage_index),
@@ -158,8 +207,18 @@ impl InterpreterCompiler {
             KaramelAstType::Primative(primative) => self.generate_primative(primative.clone(), upper_ast, context, storage_index),
             KaramelAstType::List(list) => self.generate_list(module.clone(), list, upper_ast, context, storage_index),
             KaramelAstType::Dict(dict) => self.generate_dict(module.clone(), dict, upper_ast, context, storage_index),
-            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp } => self.generate_func_call(module.clone(), func_name_expression, arguments, assign_to_temp.get(), upper_ast, context, storage_index),
-            KaramelAstType::AccessorFuncCall { source, indexer, assign_to_temp } => self.generate_accessor_func_call(module.clone(), source, indexer, assign_to_temp.get(), upper_ast, context, storage_index),
+            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp, position } => {
+                self.generate_func_call(module.clone(), func_name_expression, arguments, assign_to_temp.get(), upper_ast, context, storage_index)?;
+                context.opcode_generator.set_position(*position);
+                Ok(())
+            },
+            KaramelAstType::AccessorFuncCall { source, indexer, assign_to_temp } => {
+                self.generate_accessor_func_call(module.clone(), source, indexer, assign_to_temp.get(), upper_ast, context, storage_index)?;
+                if let KaramelAstType::FuncCall { position, .. } = &**indexer {
+                    context.opcode_generator.set_position(*position);
+                }
+                Ok(())
+            },
             KaramelAstType::PrefixUnary { operator, expression, assign_to_temp } => self.generate_prefix_unary(module.clone(), operator, expression, assign_to_temp, upper_ast, context, storage_index),
             KaramelAstType::SuffixUnary(operator, expression) => self.generate_suffix_unary(operator, expression, upper_ast, context, storage_index),
             KaramelAstType::NewLine => Ok(()),
@@ -170,9 +229,12 @@ impl InterpreterCompiler {
             KaramelAstType::IfStatement {condition, body, else_body, else_if} => self.generate_if_condition(module.clone(),condition, body, else_body, else_if, upper_ast, context, storage_index),
             KaramelAstType::Indexer {body, indexer} => self.generate_indexer(module.clone(), body, indexer, upper_ast, context, storage_index),
             KaramelAstType::None => self.generate_none(context, storage_index),
-            KaramelAstType::FunctionDefination{name: _, arguments: _, body: _} => Ok(()),
+            KaramelAstType::FunctionDefination{name: _, arguments: _, argument_positions: _, body: _, position: _} => Ok(()),
             KaramelAstType::ModulePath(name) => self.generate_function_map(name, context, storage_index),
             KaramelAstType::Load(names) => self.generate_load_module(names, context),
+
+            /* Spread is only valid as a function call argument, calls are generating it */
+            KaramelAstType::Spread(_) => Err(KaramelErrorType::SpreadArgumentNotValid)
         }
     }
 
@@ -190,6 +252,11 @@ impl InterpreterCompiler {
     }
 
     fn generate_load_module(&self, params: &[String], context: &mut KaramelCompilerContext) -> CompilerResult {
+        /* Modules are loaded before the code generation */
+        if context.has_module(&params.to_vec()) {
+            return Ok(());
+        }
+
         let mut path = PathBuf::from(&context.execution_path.path[..]);
         let module = &params[(params.len() - 1)];
 
@@ -206,7 +273,7 @@ impl InterpreterCompiler {
                     _ => ()
                 };
     
-                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                let syntax = SyntaxParser::new(parser.tokens());
                 match syntax.parse() {
                     Ok(ast) => ast,
                     Err(error) => return Err(KaramelErrorType::from(error))
@@ -226,10 +293,12 @@ impl InterpreterCompiler {
         let name = params[params.len() - 1].to_string();
         let module_path = params[0..(params.len() - 1)].to_vec();
 
-        let function_search = context.get_function(&name, &module_path, storage_index);
+        let function_search = context.get_function(&name, &module_path, storage_index)
+            .map(|reference| Rc::new(KaramelPrimative::Function(reference, None)))
+            .or_else(|| context.get_module_constant(&name[..], &module_path));
         match function_search {
-            Some(reference) => {
-                let result = storage.get_constant_location(Rc::new(KaramelPrimative::Function(reference, None)));
+            Some(constant) => {
+                let result = storage.get_constant_location(constant);
                 match result {
                     Some(index) => {
                         context.opcode_generator.create_constant(index);
@@ -256,7 +325,7 @@ impl InterpreterCompiler {
     }
 
     fn generate_list(&self, module: Rc<OpcodeModule>, list: &Vec<Rc<KaramelAstType>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
-        for item in list.iter().rev() {
+        for item in list.iter() {
             self.generate_opcode(module.clone(), item, upper_ast, context, storage_index)?;
         }
         context.opcode_generator.create_init_list(list.len());
@@ -264,8 +333,8 @@ impl InterpreterCompiler {
     }
 
     fn generate_dict(&self, module: Rc<OpcodeModule>, dict: &Vec<Rc<KaramelDictItem>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
-        for item in dict.iter().rev() {
-            self.generate_primative(item.key.clone(), upper_ast, context, storage_index)?;
+        for item in dict.iter() {
+            self.generate_opcode(module.clone(), &item.key, upper_ast, context, storage_index)?;
             self.generate_opcode(module.clone(), &item.value, upper_ast, context, storage_index)?;
         }
         context.opcode_generator.create_init_dict(dict.len());
@@ -305,13 +374,17 @@ impl InterpreterCompiler {
 
     fn generate_accessor_func_call(&self, module: Rc<OpcodeModule>, source: &KaramelAstType, indexer: &KaramelAstType, assign_to_temp: bool,  upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
 
-        if let KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _ } = indexer {
+        if let KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _, position: _ } = indexer {
             match &**func_name_expression {
                 KaramelAstType::Symbol(function_name) => {
                             /* Build arguments */
-                    for argument in arguments {
-                        self.generate_opcode(module.clone(), argument, upper_ast, context, storage_index)?;
-                    }
+                    let spread = has_spread(arguments);
+                    match spread {
+                        true => self.generate_spread_arguments(module.clone(), arguments, upper_ast, context, storage_index)?,
+                        false => for argument in arguments {
+                            self.generate_opcode(module.clone(), argument, upper_ast, context, storage_index)?;
+                        }
+                    };
                     
                     self.generate_opcode(module.clone(), source, &KaramelAstType::None, context, storage_index)?;
                     //todo: Pass real object to function as a parameter.
@@ -321,7 +394,10 @@ impl InterpreterCompiler {
                         Some(location) => {
                             context.opcode_generator.create_constant(location);
                             context.opcode_generator.add_opcode(VmOpCode::GetItem);
-                            context.opcode_generator.create_call_stack(arguments.len() as u8, assign_to_temp);
+                            match spread {
+                                true => context.opcode_generator.create_call_spread(arguments.len() as u8, assign_to_temp),
+                                false => context.opcode_generator.create_call_stack(arguments.len() as u8, assign_to_temp)
+                            };
                             return Ok(());
                         },
                         _ => return Err(KaramelErrorType::FunctionNotFound(function_name.to_string()))
@@ -331,7 +407,8 @@ impl InterpreterCompiler {
                 KaramelAstType::FuncCall {
                     func_name_expression,
                     arguments,
-                    assign_to_temp: _
+                    assign_to_temp: _,
+                    position: _
                 }=> {
                     return self.generate_func_call(module.clone(), func_name_expression, arguments, true, upper_ast, context, storage_index);
                 },
@@ -345,7 +422,40 @@ impl InterpreterCompiler {
         }
     }
 
+    /// Every argument is passed as a list to `CallSpread`. Spread arguments are already lists, others are wrapped with a single item list.
+    fn generate_spread_arguments(&self, module: Rc<OpcodeModule>, arguments: &Vec<Rc<KaramelAstType>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        for argument in arguments {
+            match &**argument {
+                KaramelAstType::Spread(expression) => self.generate_opcode(module.clone(), expression, upper_ast, context, storage_index)?,
+                _ => {
+                    self.generate_opcode(module.clone(), argument, upper_ast, context, storage_index)?;
+                    context.opcode_generator.create_init_list(1);
+                }
+            };
+        }
+        Ok(())
+    }
+
+    fn generate_spread_func_call(&self, module: Rc<OpcodeModule>, func_name_expression: &KaramelAstType, arguments: &Vec<Rc<KaramelAstType>>, assign_to_temp: bool,  upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        self.generate_spread_arguments(module.clone(), arguments, upper_ast, context, storage_index)?;
+
+        /* Function is called from the stack, argument size is known after the lists are opened */
+        match func_name_expression {
+            KaramelAstType::Symbol(function_name) => self.generate_symbol(module.clone(), function_name, upper_ast, context, storage_index)
+                .map_err(|_| KaramelErrorType::FunctionNotFound(function_name.to_string()))?,
+            KaramelAstType::FuncCall {func_name_expression, arguments: inner_arguments, assign_to_temp: _, position: _} => self.generate_func_call(module.clone(), func_name_expression, inner_arguments, true, upper_ast, context, storage_index)?,
+            _ => self.generate_opcode(module.clone(), func_name_expression, upper_ast, context, storage_index)?
+        };
+
+        context.opcode_generator.create_call_spread(arguments.len() as u8, assign_to_temp);
+        Ok(())
+    }
+
     fn generate_func_call(&self, module: Rc<OpcodeModule>, func_name_expression: &KaramelAstType, arguments: &Vec<Rc<KaramelAstType>>, assign_to_temp: bool,  upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        if has_spread(arguments) {
+            return self.generate_spread_func_call(module, func_name_expression, arguments, assign_to_temp, upper_ast, context, storage_index);
+        }
+
         /* Build arguments */
         for argument in arguments {
             self.generate_opcode(module.clone(), argument, upper_ast, context, storage_index)?;
@@ -363,7 +473,7 @@ impl InterpreterCompiler {
                 }
             },
 
-            KaramelAstType::FuncCall {func_name_expression, arguments: inner_arguments, assign_to_temp: _} => {
+            KaramelAstType::FuncCall {func_name_expression, arguments: inner_arguments, assign_to_temp: _, position: _} => {
                 self.generate_func_call(module.clone(), func_name_expression, inner_arguments, true, upper_ast, context, storage_index)?;
                 context.opcode_generator.create_call_stack(arguments.len() as u8, assign_to_temp);
                 return Ok(());
@@ -399,11 +509,51 @@ impl InterpreterCompiler {
     }
 
     fn generate_return(&self, module: Rc<OpcodeModule>, expression: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        if self.generate_tail_call(module.clone(), expression, upper_ast, context, storage_index)? {
+            return Ok(());
+        }
+
+        /* Return restores the stack of the caller, so it could be used inside of the loops without any cleanup */
         self.generate_opcode(module.clone(), expression, upper_ast, context, storage_index)?;
         context.opcode_generator.add_opcode(VmOpCode::Return);
         Ok(())
     }
 
+    /// Self-recursive call at the return position reuses the current frame.
+    /// New argument values are stored into the argument locations and execution jumps back
+    /// to the beginning of the function body instead of creating a new scope.
+    fn generate_tail_call(&self, module: Rc<OpcodeModule>, expression: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> Result<bool, KaramelErrorType> {
+        let (function_name, arguments) = match expression {
+            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _, position: _ } => match &**func_name_expression {
+                KaramelAstType::Symbol(function_name) => (function_name, arguments),
+                _ => return Ok(false)
+            },
+            _ => return Ok(false)
+        };
+
+        let body_location = match context.opcode_generator.function_body_location() {
+            Some(location) => location,
+            None => return Ok(false)
+        };
+
+        match context.get_function(function_name.to_string(), module.get_path(), storage_index) {
+            Some(function) if function.storage_index == storage_index && function.arguments.len() == arguments.len() && !has_spread(arguments) => (),
+            _ => return Ok(false)
+        };
+
+        for argument in arguments {
+            self.generate_opcode(module.clone(), argument, upper_ast, context, storage_index)?;
+        }
+
+        /* Arguments are always at the beginning of the frame */
+        for location in (0..arguments.len()).rev() {
+            context.opcode_generator.create_store(location as u8);
+        }
+
+        context.opcode_generator.create_jump(body_location);
+        Ok(true)
+    }
+
     fn generate_loop(&self, module: Rc<OpcodeModule>, loop_type: &LoopType, body: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         /* Backup loop informations */
         context.opcode_generator.loop_started();
@@ -442,8 +592,10 @@ impl InterpreterCompiler {
             };
         }
 
-        self.generate_opcode(module.clone(), body, upper_ast, context, storage_index)?;
+        self.generate_statement(module.clone(), body, upper_ast, context, storage_index)?;
 
+        /* 'devam' should execute increment part of the loop */
+        let continue_location = context.opcode_generator.current_location();
         if let Some(increment) = &increment {
             self.generate_opcode(module.clone(), &*&increment, upper_ast, context, storage_index)?;
         }
@@ -456,7 +608,7 @@ impl InterpreterCompiler {
         }
 
         context.opcode_generator.set_breaks_locations(end_location.clone());
-        context.opcode_generator.set_continues_locations(start_location.clone());
+        context.opcode_generator.set_continues_locations(continue_location.clone());
 
         context.opcode_generator.loop_finished();
 
@@ -486,6 +638,7 @@ impl InterpreterCompiler {
         match storage.get_variable_location(variable) {
             /* Variable found */
             Some(index) => {
+                context.storages[storage_index].set_variable_used(index);
                 context.opcode_generator.create_load(index);
                 Ok(())
             },
@@ -495,12 +648,14 @@ impl InterpreterCompiler {
     }
 
     fn generate_control(&self, module: Rc<OpcodeModule>, left_ast: &KaramelAstType, operator: &KaramelOperatorType, right_ast: &KaramelAstType, _: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        if *operator == KaramelOperatorType::And || *operator == KaramelOperatorType::Or {
+            return self.generate_logical(module.clone(), left_ast, operator, right_ast, context, storage_index);
+        }
+
         self.generate_opcode(module.clone(), left_ast, &KaramelAstType::None, context, storage_index)?;
         self.generate_opcode(module.clone(), right_ast, &KaramelAstType::None, context, storage_index)?;
 
         let opcode = match operator {
-            KaramelOperatorType::Or               => VmOpCode::Or,
-            KaramelOperatorType::And              => VmOpCode::And,
             KaramelOperatorType::Equal            => VmOpCode::Equal,
             KaramelOperatorType::NotEqual         => VmOpCode::NotEqual,
             KaramelOperatorType::GreaterThan      => VmOpCode::GreaterThan,
@@ -512,6 +667,39 @@ impl InterpreterCompiler {
         Ok(())
     }
 
+    /// 've' and 'veya' return the operand that decides the result, right side is not executed when the left side decides.
+    fn generate_logical(&self, module: Rc<OpcodeModule>, left_ast: &KaramelAstType, operator: &KaramelOperatorType, right_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        /*
+        ╔════════════════════╗
+        ║   LEFT SIDE        ║
+        ╠════════════════════╣
+        ║   DUPLICATE        ║
+        ╠════════════════════╣
+        ║   NOT (veya)       ║
+        ╠════════════════════╣
+        ║   COMPARE          ║──╗ Left side is the result
+        ╠════════════════════╣  ║
+        ║   POP              ║  ║
+        ╠════════════════════╣  ║
+        ║   RIGHT SIDE       ║  ║
+        ╠════════════════════╣  ║
+        ║   ....             ║◄═╝
+        ╚════════════════════╝
+        */
+        self.generate_opcode(module.clone(), left_ast, &KaramelAstType::None, context, storage_index)?;
+        context.opcode_generator.add_opcode(VmOpCode::Duplicate);
+
+        if *operator == KaramelOperatorType::Or {
+            context.opcode_generator.add_opcode(VmOpCode::Not);
+        }
+
+        let left_decided_location = self.create_compare(context);
+        context.opcode_generator.add_opcode(VmOpCode::Pop);
+        self.generate_opcode(module.clone(), right_ast, &KaramelAstType::None, context, storage_index)?;
+        context.opcode_generator.subtract_location(left_decided_location.clone(), context.opcode_generator.build_current_location(), left_decided_location.clone());
+        Ok(())
+    }
+
     fn generate_assignment(&self, module: Rc<OpcodeModule>, variable: &KaramelAstType, operator: &KaramelOperatorType, expression_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         match variable {
             KaramelAstType::Symbol(symbol) => {
@@ -520,6 +708,11 @@ impl InterpreterCompiler {
                 if let KaramelAstType::Symbol(variable_name) = variable {
                     self.check_prohibited_names(variable_name)?;    
                 }
+
+                /* Function names can not be overwritten */
+                if context.get_function(symbol, module.get_path(), storage_index).is_some() {
+                    return Err(KaramelErrorType::CannotAssignToFunction(symbol.to_string()));
+                }
                 
                 let location = context.storages.get_mut(storage_index).unwrap().add_variable(&*symbol);
                 let storage = &context.storages[storage_index];
@@ -582,7 +775,8 @@ impl InterpreterCompiler {
             KaramelOperatorType::Subtraction    => VmOpCode::Subraction,
             KaramelOperatorType::Multiplication => VmOpCode::Multiply,
             KaramelOperatorType::Division       => VmOpCode::Division,
-            KaramelOperatorType::Modulo         => VmOpCode::Module,
+            KaramelOperatorType::Modulo         => VmOpCode::Modulo,
+            KaramelOperatorType::Exponent       => VmOpCode::Exponent,
             _ => return Err(KaramelErrorType::OperatorNotValid)
         };
 
@@ -613,7 +807,8 @@ impl InterpreterCompiler {
     
             context.opcode_generator.add_opcode(opcode);
 
-            // Keep value at the stack if assign_to_temp is true
+            // Keep value at the stack if assign_to_temp is true, it is only set inside of expressions
+            // so the kept value is always consumed. Otherwise the value would stay at the stack.
             match assign_to_temp.get() {
                 true => context.opcode_generator.create_copy_to_store(location),
                 false => context.opcode_generator.create_store(location),
@@ -665,7 +860,7 @@ impl InterpreterCompiler {
         
         self.generate_opcode(module.clone(), condition, upper_ast, context, storage_index)?;
         let mut if_failed_location = self.create_compare(context);
-        self.generate_opcode(module.clone(), body, upper_ast, context, storage_index)?;
+        self.generate_statement(module.clone(), body, upper_ast, context, storage_index)?;
 
         if !else_if.is_empty() || else_body.is_some() {
             /* After executing body, need to exit from 'if condition'.
@@ -682,7 +877,7 @@ impl InterpreterCompiler {
 
             if_failed_location = self.create_compare(context);
 
-            self.generate_opcode(module.clone(), &else_if_item.body, upper_ast, context, storage_index)?;
+            self.generate_statement(module.clone(), &else_if_item.body, upper_ast, context, storage_index)?;
 
             /* Jump to out of if condition */
             self.create_exit_jump(context, &mut exit_locations);
@@ -691,7 +886,7 @@ impl InterpreterCompiler {
         context.opcode_generator.subtract_location(if_failed_location.clone(), context.opcode_generator.build_current_location(), if_failed_location.clone());
 
         if let Some(_else_body) = else_body {
-            self.generate_opcode(module.clone(), _else_body, upper_ast, context, storage_index)?;
+            self.generate_statement(module.clone(), _else_body, upper_ast, context, storage_index)?;
         }
 
         for exit_location in exit_locations {
@@ -717,7 +912,7 @@ impl InterpreterCompiler {
             };
 
             context.opcode_generator.create_load(location);
-            context.opcode_generator.add_opcode(VmOpCode::Dublicate);
+            context.opcode_generator.add_opcode(VmOpCode::Duplicate);
 
             let opcode = match operator {
                 KaramelOperatorType::Increment  => VmOpCode::Increment,
@@ -736,10 +931,55 @@ impl InterpreterCompiler {
 
     fn generate_block(&self, module: Rc<OpcodeModule>, asts: &[Rc<KaramelAstType>], upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         for ast in asts {
-            self.generate_opcode(module.clone(), &ast, upper_ast, context, storage_index)?;
+            self.generate_statement(module.clone(), &ast, upper_ast, context, storage_index)?;
+        }
+        Ok(())
+    }
+
+    /// Main program keeps the value of the last statement at the stack, it is the result of the program.
+    fn generate_main(&self, module: Rc<OpcodeModule>, ast: &KaramelAstType, context: &mut KaramelCompilerContext) -> CompilerResult {
+        match ast {
+            KaramelAstType::Block(asts) if !asts.is_empty() => {
+                self.generate_block(module.clone(), &asts[..asts.len() - 1], &KaramelAstType::None, context, 0)?;
+                self.generate_opcode(module.clone(), &asts[asts.len() - 1], &KaramelAstType::None, context, 0)
+            },
+            _ => self.generate_opcode(module.clone(), ast, &KaramelAstType::None, context, 0)
+        }
+    }
+
+    /// Value of an expression statement is not used, it is removed from the stack.
+    fn generate_statement(&self, module: Rc<OpcodeModule>, ast: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        self.generate_opcode(module.clone(), ast, upper_ast, context, storage_index)?;
+        if self.leaves_value(ast) {
+            context.opcode_generator.add_opcode(VmOpCode::Pop);
         }
         Ok(())
     }
+
+    fn leaves_value(&self, ast: &KaramelAstType) -> bool {
+        match ast {
+            KaramelAstType::Symbol(_) |
+            KaramelAstType::Control { .. } |
+            KaramelAstType::Binary { .. } |
+            KaramelAstType::Primative(_) |
+            KaramelAstType::List(_) |
+            KaramelAstType::Dict(_) |
+            KaramelAstType::Indexer { .. } |
+            KaramelAstType::SuffixUnary(_, _) |
+            KaramelAstType::ModulePath(_) |
+            KaramelAstType::None => true,
+
+            /* Calls and prefix operators only keep their value when it is used */
+            KaramelAstType::FuncCall { assign_to_temp, .. } |
+            KaramelAstType::AccessorFuncCall { assign_to_temp, .. } => assign_to_temp.get(),
+            KaramelAstType::PrefixUnary { operator, assign_to_temp, .. } => *operator == KaramelOperatorType::Not || assign_to_temp.get(),
+            _ => false
+        }
+    }
+}
+
+fn has_spread(arguments: &[Rc<KaramelAstType>]) -> bool {
+    arguments.iter().any(|argument| matches!(**argument, KaramelAstType::Spread(_)))
 }
 
 
@@ -1,12 +1,23 @@
 use std::borrow::Borrow;
-use std::{cell::RefCell, ptr, rc::Rc};
+use std::{cell::RefCell, collections::{HashMap, HashSet}, ptr, rc::Rc};
+use bitflags::bitflags;
 use crate::buildin::num::{NumModule};
 
 use crate::types::VmObject;
+use crate::constants::KARAMEL_FILE_EXTENSION;
+use crate::error::{KaramelDiagnostic, KaramelStackFrame};
 use crate::{buildin::{Class, Module, ModuleCollection, base_functions, class::{dict, get_empty_class, list, number, proxy, text}, debug, io}, compiler::scope::Scope};
 
 use super::generator::OpcodeGenerator;
-use super::{KaramelPrimative, StaticStorage, function::{FunctionReference, FunctionType, FunctionFlag}, module::OpcodeModule};
+use super::{KaramelPrimative, StaticStorage, function::{FunctionReference, FunctionType, FunctionFlag}, module::{FileModuleLoader, ModuleLoader, OpcodeModule}};
+
+/// Method that is resolved at a call site. It is used again while the receiver type and the method name stay the same.
+#[derive(Clone)]
+pub struct MethodCache {
+    pub discriminant: usize,
+    pub name: Rc<String>,
+    pub function: Rc<FunctionReference>
+}
 
 #[derive(Default)]
 pub struct ExecutionPathInfo {
@@ -14,9 +25,47 @@ pub struct ExecutionPathInfo {
     pub script: Option<String>
 }
 
+bitflags! {
+    pub struct ContextCapability: u32 {
+        const NONE           = 0b00000000;
+        const IO             = 0b00000001;
+        const MODULE_LOADING = 0b00000010;
+        const ALL            = Self::IO.bits | Self::MODULE_LOADING.bits;
+    }
+}
+
+/// Initial settings for a compiler context. Limits are disabled when they are `None`.
+#[derive(Clone)]
+pub struct ContextConfig {
+    pub script_path: Option<String>,
+    pub search_paths: Vec<String>,
+    pub capabilities: ContextCapability,
+    pub max_call_depth: Option<usize>,
+    pub instruction_limit: Option<usize>,
+    /// Extension added to module and script names, with the leading dot.
+    pub file_extension: String,
+    /// Warn when a function variable hides a variable of an outer scope.
+    pub shadowing_warnings: bool
+}
+
+impl Default for ContextConfig {
+    fn default() -> Self {
+        ContextConfig {
+            script_path: None,
+            search_paths: Vec::new(),
+            capabilities: ContextCapability::ALL,
+            max_call_depth: None,
+            instruction_limit: None,
+            file_extension: KARAMEL_FILE_EXTENSION.to_string(),
+            shadowing_warnings: false
+        }
+    }
+}
+
 const MAX_STACK: usize = 64 * 1024 + 1;
 
 pub struct KaramelCompilerContext {
+    pub config: ContextConfig,
     pub execution_path: ExecutionPathInfo,
     pub opcodes : Vec<u8>,
     pub storages: Vec<StaticStorage>,
@@ -38,13 +87,39 @@ pub struct KaramelCompilerContext {
     pub primative_classes: Vec<Rc<dyn Class>>,
     pub opcode_generator: OpcodeGenerator,
     pub stack: [VmObject; MAX_STACK],
-    pub stack_ptr: *mut VmObject
+    pub stack_ptr: *mut VmObject,
+    pub diagnostics: Vec<KaramelDiagnostic>,
+
+    /// Source of the modules, `FileModuleLoader` reads them from the file system.
+    pub module_loader: Rc<dyn ModuleLoader>,
+
+    /// Host modules that are added when a script loads them by name.
+    pub registered_modules: HashMap<String, Rc<dyn Module>>,
+
+    /// Inline method caches, indexed by the opcode location of the call site.
+    pub method_caches: Vec<Option<MethodCache>>,
+
+    /// Opcodes executed by `run_vm`, functions called from native functions are counted too.
+    pub instruction_count: usize,
+
+    /// Identical text literals and symbol names share the same allocation.
+    pub string_pool: HashSet<Rc<String>>
 }
 
 impl  KaramelCompilerContext {
     pub fn new() -> KaramelCompilerContext {
+        KaramelCompilerContext::with_config(ContextConfig::default())
+    }
+
+    pub fn with_config(config: ContextConfig) -> KaramelCompilerContext {
+        let execution_path = ExecutionPathInfo {
+            path: config.script_path.clone().unwrap_or_default(),
+            script: None
+        };
+
         let mut compiler = KaramelCompilerContext {
-            execution_path: ExecutionPathInfo::default(),
+            config,
+            execution_path,
             opcodes: Vec::new(),
             storages: vec![StaticStorage::new(0)],
             storages_ptr: ptr::null_mut(),
@@ -65,7 +140,13 @@ impl  KaramelCompilerContext {
             stack: [VmObject(0); MAX_STACK],
             stack_ptr: ptr::null_mut(),
             memory_dump: None,
-            opcode_dump: None
+            opcode_dump: None,
+            diagnostics: Vec::new(),
+            module_loader: Rc::new(FileModuleLoader),
+            registered_modules: HashMap::new(),
+            method_caches: Vec::new(),
+            instruction_count: 0,
+            string_pool: HashSet::new()
         };
         
         compiler.primative_classes.push(number::get_primative_class());
@@ -80,9 +161,13 @@ impl  KaramelCompilerContext {
         compiler.primative_classes.push(get_empty_class());
 
         compiler.add_module(base_functions::BaseFunctionsModule::new());
-        compiler.add_module(io::IoModule::new());
+        if compiler.config.capabilities.contains(ContextCapability::IO) {
+            compiler.add_module(io::IoModule::new());
+        }
         compiler.add_module(NumModule::new());
         compiler.add_module(debug::DebugModule::new());
+        #[cfg(feature = "patternMatching")]
+        compiler.add_module(crate::buildin::pattern::PatternModule::new());
 
         for _ in 0..32 {
             compiler.scopes.push(Scope::empty());
@@ -106,6 +191,19 @@ impl  KaramelCompilerContext {
         }
     }
 
+    pub fn get_module_constant<T: Borrow<str>>(&self, name: T, module_path: &Vec<String>) -> Option<Rc<KaramelPrimative>> {
+        self.modules.iter()
+            .find(|(_, module)| module.get_path() == module_path)
+            .and_then(|(_, module)| module.get_constant(name.borrow()))
+    }
+
+    /// Native module is only visible to scripts after `name yükle`. Name uses
+    /// `::` for nested paths and should match the path of the module, it is
+    /// checked before the module loader and the file system.
+    pub fn register_module<T: Borrow<str>>(&mut self, name: T, module: Rc<dyn Module>) {
+        self.registered_modules.insert(name.borrow().to_string(), module);
+    }
+
     pub fn add_function(&mut self, information: Rc<FunctionReference>) {
         self.functions.push(information);
     }
@@ -152,6 +250,65 @@ impl  KaramelCompilerContext {
         }
     }
 
+    pub fn intern(&mut self, text: Rc<String>) -> Rc<String> {
+        match self.string_pool.get(&text) {
+            Some(pooled) => pooled.clone(),
+            None => {
+                self.string_pool.insert(text.clone());
+                text
+            }
+        }
+    }
+
+    /// Texts are replaced with the pooled ones, other primatives are returned as is.
+    pub fn intern_primative(&mut self, primative: Rc<KaramelPrimative>) -> Rc<KaramelPrimative> {
+        match &*primative {
+            KaramelPrimative::Text(text) => Rc::new(KaramelPrimative::Text(self.intern(text.clone()))),
+            _ => primative
+        }
+    }
+
+    pub fn get_cached_method(&self, location: usize, value: &KaramelPrimative, name: &Rc<String>) -> Option<Rc<FunctionReference>> {
+        match self.method_caches.get(location) {
+            Some(Some(cache)) if cache.discriminant == value.discriminant() && (Rc::ptr_eq(&cache.name, name) || cache.name == *name) => Some(cache.function.clone()),
+            _ => None
+        }
+    }
+
+    pub fn set_cached_method(&mut self, location: usize, value: &KaramelPrimative, name: Rc<String>, function: Rc<FunctionReference>) {
+        /* Class instances could have different methods with the same type */
+        if let KaramelPrimative::Class(_) = value {
+            return;
+        }
+
+        if self.method_caches.len() <= location {
+            self.method_caches.resize(location + 1, None);
+        }
+
+        self.method_caches[location] = Some(MethodCache {
+            discriminant: value.discriminant(),
+            name,
+            function
+        });
+    }
+
+    /// Functions in the active scopes with their call sites, the innermost comes first. It is still valid after a runtime error.
+    pub fn call_stack(&self) -> Vec<KaramelStackFrame> {
+        let last = std::cmp::min(self.scope_index, self.scopes.len().saturating_sub(1));
+        (1..=last).rev()
+            .map(|index| &self.scopes[index])
+            .filter(|scope| !scope.function.is_null())
+            .map(|scope| {
+                let position = self.opcode_generator.opcode_position(scope.call_location).unwrap_or_default();
+                KaramelStackFrame {
+                    function: unsafe { (*scope.function).name.to_string() },
+                    line: position.line,
+                    column: position.column
+                }
+            })
+            .collect()
+    }
+
     pub fn find_class(&self, name: String, _module_path: &Vec<String>, _start_storage_index: usize) -> Option<Rc<dyn Class >> {
         let primative_search = self.primative_classes.iter().find(|&item| item.get_class_name() == name);
         match primative_search {
@@ -162,5 +319,6 @@ impl  KaramelCompilerContext {
 
     pub fn reset(&mut self) {
         self.opcodes = Vec::new();
+        self.method_caches = Vec::new();
     }
 }
\ No newline at end of file
@@ -4,6 +4,8 @@ use std::cell::RefCell;
 use std::cell::Cell;
 use std::slice::Iter;
 use std::iter::Take;
+use std::marker::PhantomData;
+use std::slice;
 use bitflags::bitflags;
 
 use crate::buildin::{DummyModule, Module};
@@ -12,6 +14,7 @@ use crate::error::KaramelErrorType;
 use crate::{inc_memory_index, dec_memory_index, get_memory_index};
 use crate::types::*;
 use crate::compiler::context::KaramelCompilerContext;
+use crate::vm::interpreter;
 
 use super::module::OpcodeModule;
 use super::{KaramelPrimative, StaticStorage};
@@ -23,14 +26,19 @@ pub type NativeCall       = fn(FunctionParameter) -> NativeCallResult;
 pub type IndexerGetCall   = fn (VmObject, f64) -> NativeCallResult ;
 pub type IndexerSetCall   = fn (VmObject, f64, VmObject) -> NativeCallResult ;
 
+/// Arguments of a native function call. The stack is kept as a pointer, so the VM could be
+/// executed again from the native function while the parameter is alive.
 #[derive(Debug)]
 pub struct FunctionParameter<'a> {
-    stack: &'a [VmObject], 
+    stack: *const VmObject,
+    stack_size: usize,
     source: Option<VmObject>, 
     last_position: usize, 
     arg_size: u8,
     stdout: &'a Option<RefCell<String>>,
-    stderr: &'a Option<RefCell<String>>
+    stderr: &'a Option<RefCell<String>>,
+    context: Option<*mut KaramelCompilerContext>,
+    lifetime: PhantomData<&'a [VmObject]>
 }
 
 pub struct FunctionParameterIterator<'a> {
@@ -39,7 +47,20 @@ pub struct FunctionParameterIterator<'a> {
 
 impl<'a> FunctionParameter<'a> {
     pub fn new(stack: &'a [VmObject], source: Option<VmObject>, last_position: usize, arg_size: u8, stdout: &'a Option<RefCell<String>>, stderr: &'a Option<RefCell<String>>) -> Self {
-        FunctionParameter { stack, source, last_position, arg_size, stdout, stderr }
+        FunctionParameter { stack: stack.as_ptr(), stack_size: stack.len(), source, last_position, arg_size, stdout, stderr, context: None, lifetime: PhantomData }
+    }
+
+    pub(crate) fn with_context(mut self, context: *mut KaramelCompilerContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Executes a function value with the given arguments and returns the result of it.
+    pub fn call(&self, callable: VmObject, arguments: &[VmObject]) -> NativeCallResult {
+        match self.context {
+            Some(context) => unsafe { interpreter::call_function(&mut *context, callable, arguments) },
+            None => Err(KaramelErrorType::NotCallable(callable.deref()))
+        }
     }
 
     pub fn source(&self) -> Option<VmObject> {
@@ -66,7 +87,7 @@ impl<'a> FunctionParameter<'a> {
     pub fn iter(&self) -> FunctionParameterIterator {
         FunctionParameterIterator 
         { 
-            iter: self.stack.iter().skip((self.last_position as usize - 1) - (self.arg_size as usize - 1)).take(self.arg_size as usize).clone()
+            iter: unsafe { slice::from_raw_parts(self.stack, self.stack_size) }.iter().skip(self.last_position - self.arg_size as usize).take(self.arg_size as usize).clone()
         }
     }
 }
@@ -99,6 +120,9 @@ pub struct FunctionReference {
     pub storage_index: usize,
     pub opcode_location: Cell<usize>,
     pub used_locations: RefCell<Vec<u16>>,
+
+    /// Deepest operand stack usage of the function body, calculated at compile time
+    pub max_stack_depth: Cell<usize>,
     pub opcode_body: Option<Rc<KaramelAstType>>,
     pub module: Rc<dyn Module>
 }
@@ -118,10 +142,19 @@ impl Default for FunctionType {
 
 impl FunctionReference {
     pub fn execute(&self, compiler: &mut KaramelCompilerContext, base: Option<VmObject>) -> Result<(), KaramelErrorType>{
+        unsafe {
+            let argument_size              = *compiler.opcodes_ptr.offset(1);
+            let call_return_assign_to_temp = *compiler.opcodes_ptr.offset(2) != 0;
+            self.execute_with_arguments(compiler, base, argument_size, call_return_assign_to_temp)
+        }
+    }
+
+    /// Same as `execute` but the argument size is not taken from the opcodes, it is only known at runtime for spread arguments.
+    pub fn execute_with_arguments(&self, compiler: &mut KaramelCompilerContext, base: Option<VmObject>, argument_size: u8, call_return_assign_to_temp: bool) -> Result<(), KaramelErrorType>{
         unsafe {
             match self.callback {
-                FunctionType::Native(func) => FunctionReference::native_function_call(&self, func, compiler, base),
-                FunctionType::Opcode => FunctionReference::opcode_function_call(&self,  compiler)
+                FunctionType::Native(func) => FunctionReference::native_function_call(&self, func, compiler, base, argument_size, call_return_assign_to_temp),
+                FunctionType::Opcode => FunctionReference::opcode_function_call(&self,  compiler, argument_size, call_return_assign_to_temp)
             }
         }
     }
@@ -135,6 +168,7 @@ impl FunctionReference {
             storage_index: 0,
             opcode_location: Cell::new(0),
             used_locations: RefCell::new(Vec::new()),
+            max_stack_depth: Cell::new(0),
             defined_storage_index: 0,
             opcode_body: None,
             module: Rc::new(DummyModule::new())
@@ -151,6 +185,7 @@ impl FunctionReference {
             storage_index: 0,
             opcode_location: Cell::new(0),
             used_locations: RefCell::new(Vec::new()),
+            max_stack_depth: Cell::new(0),
             defined_storage_index: 0,
             opcode_body: None,
             module
@@ -169,6 +204,7 @@ impl FunctionReference {
             defined_storage_index,
             opcode_location: Cell::new(0),
             used_locations: RefCell::new(Vec::new()),
+            max_stack_depth: Cell::new(0),
             opcode_body: Some(body.clone())
         };
 
@@ -179,10 +215,9 @@ impl FunctionReference {
         Rc::new(reference)
     }
 
-    unsafe fn native_function_call(reference: &FunctionReference, func: NativeCall, compiler: &mut KaramelCompilerContext, source: Option<VmObject>) -> Result<(), KaramelErrorType> {            
-        let total_args                 = *compiler.opcodes_ptr.offset(1);
-        let call_return_assign_to_temp = *compiler.opcodes_ptr.offset(2) != 0;
-        let parameter = FunctionParameter::new(&compiler.stack, source, get_memory_index!(compiler) as usize, karamel_dbg!(total_args), &compiler.stdout, &compiler.stderr);
+    unsafe fn native_function_call(reference: &FunctionReference, func: NativeCall, compiler: &mut KaramelCompilerContext, source: Option<VmObject>, total_args: u8, call_return_assign_to_temp: bool) -> Result<(), KaramelErrorType> {            
+        let context: *mut KaramelCompilerContext = compiler;
+        let parameter = FunctionParameter::new(&compiler.stack, source, get_memory_index!(compiler) as usize, karamel_dbg!(total_args), &compiler.stdout, &compiler.stderr).with_context(context);
 
         dump_data!(compiler, "native_function_call");
         
@@ -206,50 +241,71 @@ impl FunctionReference {
         }
     }
 
-    fn opcode_function_call(reference: &FunctionReference, options: &mut KaramelCompilerContext) -> Result<(), KaramelErrorType> {
+    fn opcode_function_call(reference: &FunctionReference, options: &mut KaramelCompilerContext, argument_size: u8, call_return_assign_to_temp: bool) -> Result<(), KaramelErrorType> {
         unsafe {
-            let argument_size              = *options.opcodes_ptr.offset(1);
-            let call_return_assign_to_temp = *options.opcodes_ptr.offset(2) != 0;
-            let old_index                  = options.opcodes_ptr.offset(2);
-            let location = reference.opcode_location.get() as isize;
-            options.opcodes_ptr            = options.opcodes_top_ptr.offset(location);
-            options.scope_index           += 1;
-
-            if argument_size != *options.opcodes_ptr {
-                return Err(KaramelErrorType::FunctionArgumentNotMatching {
-                    function: reference.name.to_string(),
-                    expected: argument_size, 
-                    found: *options.opcodes_ptr
-                });
-            }
+            let return_location            = options.opcodes_ptr.offset(2);
+            FunctionReference::enter_opcode_function(reference, options, argument_size, call_return_assign_to_temp, return_location)
+        }
+    }
+
+    /// Creates the scope of the function and moves to the first opcode of it. Arguments need to be in the stack.
+    /// After the `Return` opcode, execution continues from the opcode that comes after the `return_location`.
+    pub(crate) unsafe fn enter_opcode_function(reference: &FunctionReference, options: &mut KaramelCompilerContext, argument_size: u8, call_return_assign_to_temp: bool, return_location: *mut u8) -> Result<(), KaramelErrorType> {
+        let call_location = options.opcodes_ptr.offset_from(options.opcodes_top_ptr) as usize;
+        let location = reference.opcode_location.get() as isize;
+        options.opcodes_ptr            = options.opcodes_top_ptr.offset(location);
+        options.scope_index           += 1;
+
+        if options.scopes.len() <= options.scope_index {
+            options.scopes.resize(options.scopes.len() * 2, Scope::empty());
+            options.scopes_ptr = options.scopes.as_mut_ptr();
+        }
 
-            dec_memory_index!(options, argument_size.into());
-            dump_data!(options, "Current");
+        /* Frame is named before the checks, errors of the call are reported inside of the function */
+        let mut scope = options.scopes_ptr.add(options.scope_index);
+        (*scope).function = reference;
+        (*scope).call_location = call_location;
 
-            if options.scopes.len() <= options.scope_index {
-                options.scopes.resize(options.scopes.len() * 2, Scope::empty());
-                options.scopes_ptr = options.scopes.as_mut_ptr();
+        if let Some(max_call_depth) = options.config.max_call_depth {
+            if options.scope_index > max_call_depth {
+                return Err(KaramelErrorType::CallDepthExceeded(max_call_depth));
             }
+        }
+
+        if argument_size != *options.opcodes_ptr {
+            return Err(KaramelErrorType::FunctionArgumentNotMatching {
+                function: reference.name.to_string(),
+                expected: *options.opcodes_ptr,
+                found: argument_size
+            });
+        }
 
-            let mut scope = options.scopes_ptr.add(options.scope_index);
-            let storage = options.storages_ptr.add(reference.storage_index);
+        dec_memory_index!(options, argument_size.into());
+        dump_data!(options, "Current");
 
-            (*scope).constant_ptr = (*storage).constants.as_ptr();
-            (*scope).top_stack = options.stack_ptr;
+        let storage = options.storages_ptr.add(reference.storage_index);
 
-            (*scope).location                   = old_index;
-            (*scope).call_return_assign_to_temp = call_return_assign_to_temp;
+        (*scope).constant_ptr = (*storage).constants.as_ptr();
+        (*scope).top_stack = options.stack_ptr;
 
-            options.current_scope = scope;
-            inc_memory_index!(options, argument_size.into());
+        (*scope).location                   = return_location;
+        (*scope).call_return_assign_to_temp = call_return_assign_to_temp;
+
+        /* Reserve place for all local variables, not only for the arguments */
+        let frame_size = (*storage).variables.len();
+        if get_memory_index!(options) as usize + frame_size + reference.max_stack_depth.get() > options.stack.len() {
+            return Err(KaramelErrorType::StackOverflow(reference.name.to_string()));
         }
+
+        options.current_scope = scope;
+        inc_memory_index!(options, frame_size);
         Ok(())
     }
 }
 
 pub fn find_function_definition_type(module: Rc<OpcodeModule>, ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext, current_storage_index: usize, module_level: bool) -> CompilerResult {
     match ast.borrow() {
-        KaramelAstType::FunctionDefination { name, arguments, body  } => {
+        KaramelAstType::FunctionDefination { name, arguments, argument_positions, body, position: _ } => {
             /* Create new storage for new function */
             let new_storage_index = options.storages.len();
             options.storages.push(StaticStorage::new(new_storage_index));
@@ -264,14 +320,16 @@ pub fn find_function_definition_type(module: Rc<OpcodeModule>, ast: Rc<KaramelAs
             
             find_function_definition_type(module.clone(), body.clone(), options, new_storage_index, false)?;
 
+            /* Arguments are placed at the beginning of the frame, they need to get the first locations */
+            for (argument, position) in arguments.iter().zip(argument_positions.iter()) {
+                let location = options.storages[new_storage_index].add_variable(argument);
+                options.storages[new_storage_index].set_variable_position(location, *position);
+            }
+
             let storage_builder = StorageBuilder::new();
             storage_builder.prepare(module.clone(), ast.borrow(), new_storage_index, options)?;
 
             options.storages[current_storage_index].add_constant(Rc::new(KaramelPrimative::Function(function.clone(), None)));
-
-            for argument in arguments {
-                options.storages[new_storage_index].add_variable(argument);
-            }
         },
         KaramelAstType::Block(blocks) => {
             for block in blocks {
@@ -13,7 +13,10 @@ pub enum CallType {
     Call { constant_location: u8 },
 
     /// Call function from last stack value
-    CallStack
+    CallStack,
+
+    /// Call function from last stack value, arguments are the items of the lists before it
+    CallSpread
 }
 
 #[derive(Clone)]
@@ -38,7 +41,8 @@ impl OpcodeGeneratorTrait for CallGenerator {
                 opcodes.push(VmOpCode::Call.into());
                 opcodes.push(constant_location);
             },
-            CallType::CallStack => opcodes.push(VmOpCode::CallStack.into())
+            CallType::CallStack => opcodes.push(VmOpCode::CallStack.into()),
+            CallType::CallSpread => opcodes.push(VmOpCode::CallSpread.into())
         };
         opcodes.push(self.argument_size);
         opcodes.push(self.assign_to_temp.into());
@@ -54,9 +58,21 @@ impl OpcodeGeneratorTrait for CallGenerator {
             },
             CallType::CallStack => {
                 builder.add(opcode_index, VmOpCode::CallStack, self.argument_size.to_string(), (self.assign_to_temp as u8).to_string(), "".to_string());
+            },
+            CallType::CallSpread => {
+                builder.add(opcode_index, VmOpCode::CallSpread, self.argument_size.to_string(), (self.assign_to_temp as u8).to_string(), "".to_string());
             }
         };
     }
+
+    fn stack_effect(&self) -> isize {
+        /* Stack call also removes the function from the stack */
+        let removed = match self.call_type {
+            CallType::Call { .. } => self.argument_size as isize,
+            CallType::CallStack | CallType::CallSpread => self.argument_size as isize + 1
+        };
+        self.assign_to_temp as isize - removed
+    }
 }
 
 
@@ -117,4 +133,22 @@ mod tests {
         assert_eq!(opcodes[2], 5);
         assert_eq!(opcodes[3], 1);
     }
+
+    #[test]
+    fn test_4() {
+        let mut opcodes = Vec::new();
+        let generator = CallGenerator {
+            call_type: CallType::CallSpread,
+            argument_size: 2,
+            assign_to_temp: false
+        };
+
+        generator.generate(&mut opcodes);
+
+        assert_eq!(opcodes.len(), 3);
+        assert_eq!(opcodes[0], VmOpCode::CallSpread.into());
+        assert_eq!(opcodes[1], 2);
+        assert_eq!(opcodes[2], 0);
+        assert_eq!(generator.stack_effect(), -3);
+    }
 }
\ No newline at end of file
@@ -19,6 +19,8 @@ impl OpcodeGeneratorTrait for CompareGenerator {
         let location = opcode_to_location(index, opcodes);
         builder.add(opcode_index, VmOpCode::Compare, location.to_string(), "".to_string(), "".to_string());
     }
+
+    fn stack_effect(&self) -> isize { -1 }
 }
 
 impl CompareGenerator {
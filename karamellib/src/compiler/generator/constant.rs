@@ -17,4 +17,6 @@ impl OpcodeGeneratorTrait for ConstantGenerator {
         let opcode_index = index.fetch_add(2, Ordering::SeqCst);
         builder.add(opcode_index, VmOpCode::Constant, self.location.to_string(), "".to_string(), "".to_string());
     }
+
+    fn stack_effect(&self) -> isize { 1 }
 }
\ No newline at end of file
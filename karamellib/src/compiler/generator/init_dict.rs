@@ -21,4 +21,7 @@ impl OpcodeGeneratorTrait for InitDictGenerator {
         let opcode_index = index.fetch_add(3, Ordering::SeqCst);
         builder.add(opcode_index, VmOpCode::Init, "0".to_string(), self.argument_size.to_string(), "".to_string());
     }
+
+    /* Every item is a key and value pair */
+    fn stack_effect(&self) -> isize { 1 - (self.argument_size * 2) as isize }
 }
@@ -21,4 +21,6 @@ impl OpcodeGeneratorTrait for InitListGenerator {
         let opcode_index = index.fetch_add(3, Ordering::SeqCst);
         builder.add(opcode_index, VmOpCode::Init, "1".to_string(), self.argument_size.to_string(), "".to_string());
     }
+
+    fn stack_effect(&self) -> isize { 1 - self.argument_size as isize }
 }
@@ -1,10 +1,10 @@
-use std::{borrow::Borrow, cell::{Cell, RefCell}, cmp, collections::VecDeque, rc::Rc, sync::atomic::{AtomicUsize, Ordering}};
+use std::{borrow::Borrow, cell::{Cell, RefCell}, cmp, collections::{HashMap, VecDeque}, ops::Range, rc::Rc, sync::atomic::{AtomicUsize, Ordering}};
 
 use crate::{compiler::generator::location::DynamicLocationUpdateGenerator, constants::{DUMP_INDEX_WIDTH, DUMP_OPCODE_COLUMN_1, DUMP_OPCODE_COLUMN_2, DUMP_OPCODE_COLUMN_3, DUMP_OPCODE_TITLE, DUMP_OPCODE_WIDTH}};
 
 use self::{call::{CallGenerator, CallType}, compare::CompareGenerator, constant::ConstantGenerator, function::FunctionGenerator, init_dict::InitDictGenerator, init_list::InitListGenerator, jump::JumpGenerator, load::LoadGenerator, location::{CurrentLocationUpdateGenerator, OpcodeLocation, SubtractionGenerator}, location_group::OpcodeLocationGroup, opcode_item::OpcodeItem, store::{StoreGenerator, StoreType}};
 
-use super::{VmOpCode, function::FunctionReference};
+use super::{VmOpCode, ast::KaramelAstPosition, function::FunctionReference};
 
 pub mod opcode_item;
 pub mod location;
@@ -22,6 +22,9 @@ pub mod init_dict;
 pub trait OpcodeGeneratorTrait {
     fn generate(&self, opcodes: &mut Vec<u8>);
     fn dump<'a>(&self, builder: &'a DumpBuilder, index: Rc<AtomicUsize>, opcodes: &Vec<u8>);
+
+    /// How many values are added to (positive) or removed from (negative) the stack after the opcode executed.
+    fn stack_effect(&self) -> isize { 0 }
 }
 
 pub fn dump_single_opcode<'a, T: Borrow<String>>(builder: &'a DumpBuilder, index: usize, opcode: T, buffer: &mut String) {
@@ -121,14 +124,24 @@ impl DumpBuilder {
 
 pub struct OpcodeGenerator {
     generators: RefCell<Vec<Rc<dyn OpcodeGeneratorTrait>>>,
-    loop_groups: RefCell<VecDeque<LoopItem>>
+    loop_groups: RefCell<VecDeque<LoopItem>>,
+    function_body: RefCell<Option<Rc<OpcodeLocation>>>,
+
+    /// Source positions of the call sites, indexed by the generator.
+    positions: RefCell<HashMap<usize, KaramelAstPosition>>,
+
+    /// Opcodes of the call sites, filled by `generate`.
+    opcode_positions: RefCell<Vec<(Range<usize>, KaramelAstPosition)>>
 }
 
 impl OpcodeGenerator {
     pub fn new() -> Self {
         OpcodeGenerator {
             generators: RefCell::new(Vec::new()),
-            loop_groups: RefCell::new(VecDeque::new())
+            loop_groups: RefCell::new(VecDeque::new()),
+            function_body: RefCell::new(None),
+            positions: RefCell::new(HashMap::new()),
+            opcode_positions: RefCell::new(Vec::new())
         }
     }
 
@@ -198,6 +211,24 @@ impl OpcodeGenerator {
         self.loop_groups.borrow_mut().pop_back();
     }
 
+    /// Mark the first opcode of the function body. Tail calls jump back to that location.
+    pub fn function_started(&self) {
+        let location = self.build_current_location();
+        *self.function_body.borrow_mut() = Some(location);
+    }
+
+    pub fn function_finished(&self) {
+        *self.function_body.borrow_mut() = None;
+    }
+
+    /// Loop values could be still in the stack, so there is no body location inside of loops.
+    pub fn function_body_location(&self) -> Option<Rc<OpcodeLocation>> {
+        match self.loop_groups.borrow().is_empty() {
+            true => self.function_body.borrow().clone(),
+            false => None
+        }
+    }
+
     /// Create empty location point. It is used for jump and compare location positions.
     pub fn create_location(&self) -> Rc<OpcodeLocation> {
         Rc::new(OpcodeLocation::empty())
@@ -293,6 +324,16 @@ impl OpcodeGenerator {
         generator
     }
 
+    pub fn create_call_spread(&self, argument_size: u8, assign_to_temp: bool) -> Rc<CallGenerator> {
+        let generator = Rc::new(CallGenerator { 
+                call_type: CallType::CallSpread,
+                argument_size,
+                assign_to_temp
+             });
+        self.generators.borrow_mut().push(generator.clone());
+        generator
+    }
+
     pub fn create_function_definition(&self, function: Rc<FunctionReference>) -> Rc<FunctionGenerator> {
         let generator = Rc::new(FunctionGenerator { function: function.clone() });
         self.generators.borrow_mut().push(generator.clone());
@@ -313,9 +354,55 @@ impl OpcodeGenerator {
 }
 
 impl OpcodeGenerator {
+    pub fn len(&self) -> usize {
+        self.generators.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.generators.borrow().is_empty()
+    }
+
+    /// Source position of the last generated opcode, it is used to show the call sites in the backtraces.
+    pub fn set_position(&self, position: KaramelAstPosition) {
+        if let Some(index) = self.len().checked_sub(1) {
+            self.positions.borrow_mut().insert(index, position);
+        }
+    }
+
+    /// Source position of the opcode at the location, only the call sites have a position.
+    pub fn opcode_position(&self, location: usize) -> Option<KaramelAstPosition> {
+        self.opcode_positions.borrow().iter()
+            .find(|(range, _)| range.contains(&location))
+            .map(|(_, position)| *position)
+    }
+
+    /// Maximum stack usage of the opcodes generated after the `start` index.
+    /// Every statement leaves the stack as it found, so a linear walk gives
+    /// the deepest point of all the branches.
+    pub fn max_stack_depth(&self, start: usize) -> usize {
+        let mut depth: isize = 0;
+        let mut max_depth: isize = 0;
+
+        for generator in self.generators.borrow().iter().skip(start) {
+            depth = cmp::max(depth + generator.stack_effect(), 0);
+            max_depth = cmp::max(max_depth, depth);
+        }
+
+        max_depth as usize
+    }
+
     pub fn generate(&self, opcodes: &mut Vec<u8>) {
-        for generator in self.generators.borrow().iter() {
+        let positions = self.positions.borrow();
+        let mut opcode_positions = self.opcode_positions.borrow_mut();
+        opcode_positions.clear();
+
+        for (index, generator) in self.generators.borrow().iter().enumerate() {
+            let start = opcodes.len();
             generator.generate(opcodes);
+
+            if let Some(position) = positions.get(&index) {
+                opcode_positions.push((start..opcodes.len(), *position));
+            }
         }
     }
 
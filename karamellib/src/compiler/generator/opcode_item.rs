@@ -21,4 +21,13 @@ impl OpcodeGeneratorTrait for OpcodeItem {
         let opcode_index = index.fetch_add(1, Ordering::SeqCst);
         builder.add(opcode_index, self.opcode, "".to_string(), "".to_string(), "".to_string());
     }
+
+    fn stack_effect(&self) -> isize {
+        match self.opcode {
+            VmOpCode::Duplicate => 1,
+            VmOpCode::Not | VmOpCode::Increment | VmOpCode::Decrement | VmOpCode::Halt | VmOpCode::Swap => 0,
+            VmOpCode::SetItem => -3,
+            _ => -1
+        }
+    }
 }
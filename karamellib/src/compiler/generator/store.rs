@@ -56,4 +56,11 @@ impl OpcodeGeneratorTrait for StoreGenerator {
             }
         };
     }
+
+    fn stack_effect(&self) -> isize {
+        match self.store_type {
+            StoreType::Store(_) => -1,
+            StoreType::CopyToStore(_) | StoreType::FastStore { .. } => 0
+        }
+    }
 }
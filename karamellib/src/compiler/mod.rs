@@ -66,7 +66,10 @@ pub enum VmOpCode {
     Subraction = 2,
     Multiply = 3,
     Division = 4,
-    Module = 5,
+    /// Remainder of the division, result is `Empty` when the right side is zero like `Division`.
+    Modulo = 5,
+    /// Operand deciding the result is kept, both sides are already executed.
+    /// Compiler uses `Compare` for 've' and 'veya' so the right side is skipped when it is not needed.
     And = 6,
     Or = 7,
     Equal = 8,
@@ -80,6 +83,8 @@ pub enum VmOpCode {
 
     Increment = 19,
     Decrement = 20,
+
+    /// Replace last stack value with the opposite of its truthiness, see `KaramelPrimative::is_true`.
     Not = 21,
 
     /// Compare previous two opcode.
@@ -99,13 +104,38 @@ pub enum VmOpCode {
     /// Dublicate value at memory. Take value from memory and copy to destination location. Stack not involved at this operation.
     FastStore = 28,
 
-    /// Copy last stack value to memory and keep copied value at stack.
+    /// Copy last stack value to memory and keep copied value at stack. Only emitted for prefix
+    /// increment and decrement whose result is used by the enclosing expression (`a = ++b`), the
+    /// kept value is consumed by that expression. Statements use `Store`, so nothing is left behind.
     CopyToStore = 29,
-    Dublicate = 30,
+
+    /// Copy last stack value to the top of the stack.
+    Duplicate = 30,
     GetItem = 31,
     SetItem = 32,
     Constant = 33,
-    Halt = 34
+    Halt = 34,
+
+    /// Remove last stack value.
+    Pop = 35,
+
+    /// Exchange last two stack values.
+    Swap = 36,
+
+    /// Left value to the power of the right value, result is `Empty` when one of them is not a number.
+    Exponent = 37,
+
+    /// Same as `CallStack` but the values before the function are lists, their items are the arguments.
+    CallSpread = 38
+}
+
+#[allow(non_upper_case_globals)]
+impl VmOpCode {
+    #[deprecated(note = "`VmOpCode::Duplicate` kullanılmalı")]
+    pub const Dublicate: VmOpCode = VmOpCode::Duplicate;
+
+    #[deprecated(note = "`VmOpCode::Modulo` kullanılmalı")]
+    pub const Module: VmOpCode = VmOpCode::Modulo;
 }
 
 impl From<VmOpCode> for u8 {
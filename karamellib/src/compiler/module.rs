@@ -1,6 +1,6 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use crate::buildin::Class;
@@ -13,12 +13,42 @@ use crate::parser::Parser;
 use crate::syntax::SyntaxParser;
 use crate::types::CompilerResult;
 
-use super::context::KaramelCompilerContext;
+use super::context::{ContextCapability, KaramelCompilerContext};
 use super::ast::KaramelAstType;
 use super::function::FunctionReference;
 
 use crate::error::*;
 
+/// Provides module sources, embedders that do not keep scripts on disk can replace the default `FileModuleLoader`.
+/// `path` is the module path written in the script, `a::b yükle` gives `["a", "b"]`.
+/// `module_directory` is the directory of the importing module, `None` for the main script.
+pub trait ModuleLoader {
+    fn load(&self, path: &[String], module_directory: Option<&Path>, options: &KaramelCompilerContext) -> Result<ModuleSource, KaramelErrorType>;
+}
+
+/// Reads the modules from the file system.
+pub struct FileModuleLoader;
+
+impl ModuleLoader for FileModuleLoader {
+    fn load(&self, path: &[String], module_directory: Option<&Path>, options: &KaramelCompilerContext) -> Result<ModuleSource, KaramelErrorType> {
+        let (root_path, module_path, content) = read_module(path, module_directory, options)?;
+
+        /* Module can be a file or a directory with a startup file */
+        let directory = match module_path.is_dir() {
+            true => module_path.clone(),
+            false => module_path.parent().unwrap_or(&root_path).to_path_buf()
+        };
+
+        Ok(ModuleSource {
+            name: path.last().cloned().unwrap_or_default(),
+            file_path: module_path.to_string_lossy().to_string(),
+            path: get_module_path(&root_path, &module_path),
+            directory: Some(directory),
+            content
+        })
+    }
+}
+
 pub struct OpcodeModule {
     pub name: String,
     pub storage_index: usize,
@@ -75,10 +105,11 @@ impl Module for OpcodeModule {
     }
 }
 
-fn get_module_path(options: &KaramelCompilerContext, module_path: &PathBuf) -> Vec<String> {
+/// Non UTF-8 path parts are converted lossily, module names only need to be
+/// readable by the script.
+fn get_module_path(root_path: &Path, module_path: &Path) -> Vec<String> {
     let mut path = Vec::new();
-    let script_path = PathBuf::from(&options.execution_path.path[..]);
-    let mut script_path_iter = script_path.iter();
+    let mut script_path_iter = root_path.iter();
     let mut module_path_iter = module_path.iter();
 
     while let Some(_) = script_path_iter.next() {
@@ -86,42 +117,109 @@ fn get_module_path(options: &KaramelCompilerContext, module_path: &PathBuf) -> V
     }
     
     while let Some(path_part) = module_path_iter.next() {
-        path.push(path_part.to_str().unwrap().to_string());
+        path.push(path_part.to_string_lossy().to_string());
     }
     path
 }
 
-pub fn load_module(params: &[String], modules: &mut Vec<Rc<OpcodeModule>>, options: &mut KaramelCompilerContext, upper_storage_index: usize) -> Result<Rc<OpcodeModule>, KaramelError> {
-    let mut path = PathBuf::from(&options.execution_path.path[..]);
-    let module = params[(params.len() - 1)].to_string();
+/// Reads the module from the directory of the importing module first, then
+/// from the script path and the configured search paths.
+fn read_module(params: &[String], module_directory: Option<&Path>, options: &KaramelCompilerContext) -> Result<(PathBuf, PathBuf, String), KaramelErrorType> {
+    let mut roots = Vec::new();
+    roots.extend(module_directory.map(Path::to_path_buf));
+    roots.push(PathBuf::from(&options.execution_path.path[..]));
+    roots.extend(options.config.search_paths.iter().map(PathBuf::from));
+
+    let mut last_error = None;
+    for root in roots {
+        let mut path = root.clone();
+        for item in params.iter() {
+            path.push(item);
+        }
 
-    for item in params.iter().take(params.len() - 1) {
-        path.push(item);
+        match read_module_or_script(&path.to_string_lossy()[..], options) {
+            Ok(content) => return Ok((root, path, content)),
+            Err(error) => if last_error.is_none() {
+                last_error = Some(error)
+            }
+        };
     }
 
-    path.push(module.clone());
+    Err(last_error.unwrap())
+}
+
+/// `module_directory` is the directory of the importing module, `None` for the main script.
+pub fn load_module(params: &[String], module_directory: Option<&Path>, modules: &mut Vec<Rc<OpcodeModule>>, options: &mut KaramelCompilerContext, upper_storage_index: usize) -> Result<Rc<OpcodeModule>, KaramelError> {
+    let module = match params.last() {
+        Some(module) => module.to_string(),
+        None => return Err(KaramelError::new(0, 0, KaramelErrorType::EmptyModuleName))
+    };
+
+    if !options.config.capabilities.contains(ContextCapability::MODULE_LOADING) {
+        return Err(KaramelError::new(0, 0, KaramelErrorType::ModuleLoadingNotAllowed(params.join("::"))));
+    }
 
-    let content = match read_module_or_script(path.to_str().unwrap(), options) {
-        Ok(content) => content,
+    let loader = options.module_loader.clone();
+    let source = match loader.load(params, module_directory, options) {
+        Ok(source) => ModuleSource { name: module, ..source },
         Err(error) => return Err(KaramelError::new(0, 0, error))
     };
 
-    let mut parser = Parser::new(&content);
+    build_module(source, modules, options, upper_storage_index)
+}
+
+/// Loads a module from the given source code without touching the file system.
+/// Modules imported by it are searched from the script path.
+pub fn load_module_from_source(name: &str, source: &str, modules: &mut Vec<Rc<OpcodeModule>>, options: &mut KaramelCompilerContext, upper_storage_index: usize) -> Result<Rc<OpcodeModule>, KaramelError> {
+    build_module(ModuleSource {
+        name: name.to_string(),
+        file_path: String::new(),
+        path: vec![name.to_string()],
+        directory: None,
+        content: source.to_string()
+    }, modules, options, upper_storage_index)
+}
+
+/// Source of a module that is about to be compiled. `directory` is used to
+/// resolve the modules it imports, `None` searches from the script path.
+pub struct ModuleSource {
+    pub name: String,
+    pub file_path: String,
+    pub path: Vec<String>,
+    pub directory: Option<PathBuf>,
+    pub content: String
+}
+
+impl ModuleSource {
+    /// Source that is not read from the file system, the module path is used as the file path.
+    pub fn new(path: &[String], content: String) -> ModuleSource {
+        ModuleSource {
+            name: path.last().cloned().unwrap_or_default(),
+            file_path: path.join("::"),
+            path: path.to_vec(),
+            directory: None,
+            content
+        }
+    }
+}
+
+fn build_module(source: ModuleSource, modules: &mut Vec<Rc<OpcodeModule>>, options: &mut KaramelCompilerContext, upper_storage_index: usize) -> Result<Rc<OpcodeModule>, KaramelError> {
+    let mut parser = Parser::new(&source.content);
     parser.parse()?;
 
-    let syntax = SyntaxParser::new(parser.tokens().to_vec());
+    let syntax = SyntaxParser::new(parser.tokens());
     return match syntax.parse() {
         Ok(ast) => {
             let module_storage = options.storages.len();
             options.storages.push(StaticStorage::new(module_storage));
             options.storages[module_storage].set_parent_location(upper_storage_index);
 
-            let mut module = OpcodeModule::new(module, path.to_str().unwrap().to_string(), ast.clone());
-            module.path = get_module_path(options, &path);
+            let mut module = OpcodeModule::new(source.name, source.file_path, ast.clone());
+            module.path = source.path;
             module.storage_index = module_storage;
 
             let module = Rc::new(module);
-            find_load_type(module.main_ast.clone(), options, modules, module.storage_index)?;
+            find_load_type(module.main_ast.clone(), source.directory.as_deref(), options, modules, module.storage_index)?;
             find_function_definition_type(module.clone(), ast.clone(), options, module_storage, true).map_err(KaramelErrorType::from)?;
             Ok(module.clone())
         },
@@ -129,18 +227,23 @@ pub fn load_module(params: &[String], modules: &mut Vec<Rc<OpcodeModule>>, optio
     };
 }
 
-fn find_load_type(ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext, modules: &mut Vec<Rc<OpcodeModule>>, upper_storage_index: usize) -> CompilerResult {
+fn find_load_type(ast: Rc<KaramelAstType>, module_directory: Option<&Path>, options: &mut KaramelCompilerContext, modules: &mut Vec<Rc<OpcodeModule>>, upper_storage_index: usize) -> CompilerResult {
     match &*ast {
         KaramelAstType::Load(module_name) => {
             if !options.has_module(&module_name) {
-                let module = load_module(module_name, modules, options, upper_storage_index)?;
-                options.add_module(module.clone());
-                modules.push(module.clone());
+                match options.registered_modules.get(&module_name.join("::")).cloned() {
+                    Some(module) => options.add_module(module),
+                    None => {
+                        let module = load_module(module_name, module_directory, modules, options, upper_storage_index)?;
+                        options.add_module(module.clone());
+                        modules.push(module.clone());
+                    }
+                };
             }
         },
         KaramelAstType::Block(blocks) => {
             for block in blocks {
-                find_load_type(block.clone(), options, modules, upper_storage_index)?;
+                find_load_type(block.clone(), module_directory, options, modules, upper_storage_index)?;
             }
         },
         _ => ()
@@ -151,7 +254,7 @@ fn find_load_type(ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext,
 
 pub fn get_modules(main_ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext) -> Result<Vec<Rc<OpcodeModule>>, KaramelError> {
     let mut modules: Vec<Rc<OpcodeModule>> = Vec::new();
-    match find_load_type(main_ast, options, &mut modules, 0) {
+    match find_load_type(main_ast, None, options, &mut modules, 0) {
         Ok(()) => Ok(modules),
         Err(error) => Err(KaramelError::new(0, 0, error))
     }
@@ -164,9 +267,11 @@ mod tests {
     use std::fs::File;
     use std::io::prelude::*;
     use std::path::Path;
+    use std::rc::Rc;
 
-    use crate::compiler::context::KaramelCompilerContext;
-    use crate::compiler::module::load_module;
+    use crate::compiler::context::{ContextConfig, KaramelCompilerContext};
+    use crate::compiler::module::{load_module, load_module_from_source, ModuleLoader, ModuleSource};
+    use crate::buildin::Module;
     use crate::constants::KARAMEL_FILE_EXTENSION;
     use crate::error::KaramelErrorType;
     use crate::vm::executer::ExecutionSource;
@@ -178,7 +283,12 @@ mod tests {
 
     fn teardown(to_be_removed: Vec<String>) {
         for file in to_be_removed.iter() {
-            match std::fs::remove_file(file) {
+            let result = match Path::new(file).is_dir() {
+                true => std::fs::remove_dir(file),
+                false => std::fs::remove_file(file)
+            };
+
+            match result {
                 Ok(_) => (),
                 Err(error) => println!("'{}' silinemedi. Hata mesajı: {}", file, error)
             }
@@ -230,7 +340,7 @@ fonk topla(bir, iki): dondur bir + iki"#;
             let mut modules = Vec::new();
             let mut options = KaramelCompilerContext::new();
             options.execution_path = get_execution_path(ExecutionSource::Code("".to_string()));
-            load_module(&[String::from("topla")].to_vec(), &mut modules, &mut options, 0)?;
+            load_module(&[String::from("topla")].to_vec(), None, &mut modules, &mut options, 0)?;
             Ok(())
         }, [topla_path].to_vec())
     }
@@ -249,9 +359,130 @@ fonk topla2(bir, iki): dondur module_1::topla(bir, iki)"#;
             let mut modules = Vec::new();
             let mut options = KaramelCompilerContext::new();
             options.execution_path = get_execution_path(ExecutionSource::Code("".to_string()));
-            load_module(&[String::from("module_1")].to_vec(), &mut modules, &mut options, 1)?;
-            load_module(&[String::from("module_2")].to_vec(), &mut modules, &mut options, 0)?;
+            load_module(&[String::from("module_1")].to_vec(), None, &mut modules, &mut options, 1)?;
+            load_module(&[String::from("module_2")].to_vec(), None, &mut modules, &mut options, 0)?;
             Ok(())
         }, [module_1_path, module_2_path].to_vec())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_3() -> Result<(), KaramelErrorType> {
+        let module_1 = r#"
+fonk topla(bir, iki): dondur bir + iki"#;
+        let module_path = write_to_file(module_1, format!("arama_yolu{}", KARAMEL_FILE_EXTENSION));
+
+        run_test(|| {
+            let mut modules = Vec::new();
+            let mut options = KaramelCompilerContext::with_config(ContextConfig {
+                script_path: Some("bulunamayan_dizin".to_string()),
+                search_paths: vec![get_execution_path(ExecutionSource::Code("".to_string())).path],
+                ..ContextConfig::default()
+            });
+            let module = load_module(&[String::from("arama_yolu")].to_vec(), None, &mut modules, &mut options, 0)?;
+            assert_eq!(module.path, vec!["arama_yolu".to_string()]);
+            Ok(())
+        }, [module_path].to_vec())
+    }
+
+    #[test]
+    fn test_4() -> Result<(), KaramelErrorType> {
+        let module_a = r#"
+goreceli_b yükle
+fonk topla2(bir, iki): dondur goreceli_b::topla(bir, iki)"#;
+        let module_b = r#"
+fonk topla(bir, iki): dondur bir + iki"#;
+        let directory = generate_file_name("goreceli_alt");
+        std::fs::create_dir_all(&directory).unwrap();
+        let module_a_path = write_to_file(module_a, format!("goreceli_alt/goreceli_a{}", KARAMEL_FILE_EXTENSION));
+        let module_b_path = write_to_file(module_b, format!("goreceli_alt/goreceli_b{}", KARAMEL_FILE_EXTENSION));
+
+        run_test(|| {
+            let mut modules = Vec::new();
+            let mut options = KaramelCompilerContext::new();
+            options.execution_path = get_execution_path(ExecutionSource::Code("".to_string()));
+            let module = load_module(&[String::from("goreceli_alt"), String::from("goreceli_a")].to_vec(), None, &mut modules, &mut options, 0)?;
+            assert_eq!(module.path, vec!["goreceli_alt".to_string(), "goreceli_a".to_string()]);
+
+            assert_eq!(modules.len(), 1);
+            assert_eq!(modules[0].path, vec!["goreceli_b".to_string()]);
+            assert!(Path::new(&modules[0].file_path).ends_with("goreceli_alt/goreceli_b"));
+            Ok(())
+        }, [module_a_path, module_b_path, directory].to_vec())
+    }
+
+    #[test]
+    fn test_5() -> Result<(), KaramelErrorType> {
+        let mut modules = Vec::new();
+        let mut options = KaramelCompilerContext::new();
+        let module = load_module_from_source("bellek", r#"
+fonk topla(bir, iki): dondur bir + iki"#, &mut modules, &mut options, 0)?;
+
+        assert_eq!(module.path, vec!["bellek".to_string()]);
+        assert!(module.get_method("topla").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_6() -> Result<(), KaramelErrorType> {
+        let module_1 = r#"
+fonk topla(bir, iki): dondur bir + iki"#;
+        let module_path = write_to_file(module_1, "uzanti.kpd");
+
+        run_test(|| {
+            let mut modules = Vec::new();
+            let mut options = KaramelCompilerContext::with_config(ContextConfig {
+                script_path: Some(get_execution_path(ExecutionSource::Code("".to_string())).path),
+                file_extension: ".kpd".to_string(),
+                ..ContextConfig::default()
+            });
+            let module = load_module(&[String::from("uzanti")].to_vec(), None, &mut modules, &mut options, 0)?;
+            assert!(module.get_method("topla").is_some());
+            Ok(())
+        }, [module_path].to_vec())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_7() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::PathBuf;
+
+        let root = PathBuf::from("/kök");
+        let mut module = root.clone();
+        module.push(OsStr::from_bytes(b"al\xFFt"));
+        module.push("modül");
+
+        assert_eq!(super::get_module_path(&root, &module), vec!["al\u{FFFD}t".to_string(), "modül".to_string()]);
+    }
+
+    #[test]
+    fn test_8() {
+        let mut modules = Vec::new();
+        let mut options = KaramelCompilerContext::new();
+        let result = load_module(&Vec::new(), None, &mut modules, &mut options, 0);
+        assert_eq!(result.err().map(|error| error.error_type), Some(KaramelErrorType::EmptyModuleName));
+    }
+
+    struct SourceLoader;
+
+    impl ModuleLoader for SourceLoader {
+        fn load(&self, path: &[String], _: Option<&Path>, _: &KaramelCompilerContext) -> Result<ModuleSource, KaramelErrorType> {
+            Ok(ModuleSource::new(path, r#"
+sayılar = [1, 2, 3]
+fonk topla(bir, iki): dondur bir + iki"#.to_string()))
+        }
+    }
+
+    #[test]
+    fn test_9() -> Result<(), KaramelErrorType> {
+        let mut modules = Vec::new();
+        let mut options = KaramelCompilerContext::new();
+        options.module_loader = Rc::new(SourceLoader);
+        let module = load_module(&[String::from("kaynak")].to_vec(), None, &mut modules, &mut options, 0)?;
+
+        assert_eq!(module.path, vec!["kaynak".to_string()]);
+        assert!(module.get_method("topla").is_some());
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use std::ptr;
 
 use crate::types::VmObject;
+use crate::compiler::function::FunctionReference;
 
 
 #[derive(Clone)]
@@ -8,7 +9,13 @@ pub struct Scope {
     pub location: *mut u8,
     pub call_return_assign_to_temp: bool,
     pub top_stack: *mut VmObject,
-    pub constant_ptr: *const VmObject
+    pub constant_ptr: *const VmObject,
+
+    /// Function that owns the scope, null for the main program.
+    pub function: *const FunctionReference,
+
+    /// Opcode location of the caller while the function is called.
+    pub call_location: usize
 }
 
 impl Scope {
@@ -17,7 +24,9 @@ impl Scope {
             call_return_assign_to_temp: false, 
             location: ptr::null_mut(), 
             top_stack: ptr::null_mut(), 
-            constant_ptr: ptr::null()
+            constant_ptr: ptr::null(),
+            function: ptr::null(),
+            call_location: 0
         }
     }
 }
\ No newline at end of file
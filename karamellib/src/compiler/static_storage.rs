@@ -1,18 +1,22 @@
 use crate::buildin::Module;
 use crate::types::*;
 use crate::compiler::*;
+use crate::compiler::ast::KaramelAstPosition;
 use std::rc::Rc;
 
 #[cfg(not(feature = "unittest"))]
 use crate::{debug_println};
 
 use std::ptr;
+use std::fmt::{self, Write};
 
 pub struct StaticStorage {
     pub index                 : usize,
     pub constants             : Vec<VmObject>,
     pub constants_ptr         : *const VmObject,
     pub variables             : Vec<String>,
+    pub used_variables        : Vec<bool>,
+    pub variable_positions    : Vec<Option<KaramelAstPosition>>,
     pub parent_location       : Option<usize>
 }
 
@@ -23,6 +27,8 @@ impl StaticStorage {
             constants: Vec::with_capacity(128),
             constants_ptr: ptr::null(),
             variables: Vec::new(),
+            used_variables: Vec::new(),
+            variable_positions: Vec::new(),
             parent_location: None
         };
         storage.constants_ptr = storage.constants.as_ptr();
@@ -56,6 +62,8 @@ impl StaticStorage {
             Some(location) => location as u8,
             _ => {
                 self.variables.push(name.to_string());
+                self.used_variables.push(false);
+                self.variable_positions.push(None);
                 (self.variables.len()-1) as u8
             }
         }
@@ -69,6 +77,25 @@ impl StaticStorage {
         }
     }
 
+    pub fn set_variable_used(&mut self, location: u8) {
+        if let Some(used) = self.used_variables.get_mut(location as usize) {
+            *used = true;
+        }
+    }
+
+    /// Keeps the position of the first assignment of the variable.
+    pub fn set_variable_position(&mut self, location: u8, position: KaramelAstPosition) {
+        if let Some(item) = self.variable_positions.get_mut(location as usize) {
+            if item.is_none() {
+                *item = Some(position);
+            }
+        }
+    }
+
+    pub fn get_variable_position(&self, location: u8) -> Option<KaramelAstPosition> {
+        self.variable_positions.get(location as usize).copied().flatten()
+    }
+
     pub fn get_constant_location(&self, value: Rc<KaramelPrimative>) -> Option<u8> {
         return match self.constants.iter().position(|x| { *x.deref() == *value }) {
             Some(number) => Some(number as u8),
@@ -109,20 +136,25 @@ impl StaticStorage {
     #[cfg(not(feature = "unittest"))]
     pub fn dump(&self) -> String {
         let mut buffer = String::new();
-        buffer.push_str("╔════════════════════════════════════════╗\n");
-        buffer.push_str("║             VARIABLE DUMP              ║\n");
-        buffer.push_str("╠═════╦══════════════════════════════════╣\n");
+        self.dump_to(&mut buffer).unwrap();
+        buffer
+    }
+
+    /// Renders variable slots with their names and the constants into the writer.
+    pub fn dump_to<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        writer.write_str("╔════════════════════════════════════════╗\n")?;
+        writer.write_str("║             VARIABLE DUMP              ║\n")?;
+        writer.write_str("╠═════╦══════════════════════════════════╣\n")?;
         for (index, variable) in self.variables.iter().enumerate() {
-            buffer.push_str(&format!("║ {:3?} ║ {:32} ║\n", index, format!("{}", variable))[..]);
+            writeln!(writer, "║ {:3?} ║ {:32} ║", index, variable)?;
         }
-        buffer.push_str("╚═════╩══════════════════════════════════╝\n");
-        buffer.push_str("╔════════════════════════════════════════╗\n");
-        buffer.push_str("║             CONSTANT DUMP              ║\n");
-        buffer.push_str("╠═════╦══════════════════════════════════╣\n");
+        writer.write_str("╚═════╩══════════════════════════════════╝\n")?;
+        writer.write_str("╔════════════════════════════════════════╗\n")?;
+        writer.write_str("║             CONSTANT DUMP              ║\n")?;
+        writer.write_str("╠═════╦══════════════════════════════════╣\n")?;
         for (index, constant) in self.constants.iter().enumerate() {
-            buffer.push_str(&format!("║ {:3?} ║ {:32} ║\n", index, format!("{}", constant))[..]);
+            writeln!(writer, "║ {:3?} ║ {:32} ║", index, format!("{}", constant))?;
         }
-        buffer.push_str("╚═════╩══════════════════════════════════╝\n");
-        buffer
+        writer.write_str("╚═════╩══════════════════════════════════╝\n")
     }
 }
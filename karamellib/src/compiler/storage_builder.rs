@@ -19,7 +19,7 @@ impl StorageBuilder {
         Ok(())
     }
 
-    fn build(&self, module: Rc<OpcodeModule>, ast: &KaramelAstType, _: &KaramelAstType, options: &mut KaramelCompilerContext, storage_index: usize) -> Result<(), KaramelErrorType> {
+    fn build(&self, module: Rc<OpcodeModule>, ast: &KaramelAstType, parent: &KaramelAstType, options: &mut KaramelCompilerContext, storage_index: usize) -> Result<(), KaramelErrorType> {
         use crate::buildin::Module;
         
         match ast {
@@ -81,15 +81,25 @@ impl StorageBuilder {
                 let function_search = options.get_function(&name, &module_path, storage_index);
                 match function_search {
                     Some(reference) => options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Function(reference, None))),
-                    None => return Err(KaramelErrorType::FunctionNotFound(name.to_string()))
+                    None => match options.get_module_constant(&name[..], &module_path) {
+                        Some(constant) => options.storages.get_mut(storage_index).unwrap().add_constant(constant),
+                        None => return Err(KaramelErrorType::FunctionNotFound(name.to_string()))
+                    }
                 };
             },
             
             KaramelAstType::Assignment {
                 variable,
                 operator: _,
-                expression} =>  {
+                expression,
+                position} =>  {
                 self.build(module.clone(),variable, ast, options, storage_index)?;                
+                if let KaramelAstType::Symbol(name) = &**variable {
+                    let storage = options.storages.get_mut(storage_index).unwrap();
+                    if let Some(location) = storage.get_variable_location(name) {
+                        storage.set_variable_position(location, *position);
+                    }
+                }
                 self.build(module.clone(),expression, ast, options, storage_index)?;
             },
             
@@ -122,7 +132,7 @@ impl StorageBuilder {
 ║   Function Pointer   ║
 ╚══════════════════════╝
  */
-            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _ } => {
+            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _, position: _ } => {
                 /* Build arguments */
                 for arg in arguments {
                     self.build(module.clone(),arg, ast, options, storage_index)?;
@@ -137,7 +147,8 @@ impl StorageBuilder {
                             options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Function(reference, None)));
                         }
                         else {
-                            options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Text(Rc::new(function_name.to_string()))));
+                            let function_name = options.intern(Rc::new(function_name.to_string()));
+                            options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Text(function_name)));
                         }
                     },
                     KaramelAstType::ModulePath(names) => {
@@ -157,6 +168,10 @@ impl StorageBuilder {
                 self.build(module.clone(),expression, ast, options, storage_index)?;
             },
 
+            KaramelAstType::Spread(expression) => {
+                self.build(module.clone(),expression, ast, options, storage_index)?;
+            },
+
             KaramelAstType::Loop {
                 loop_type,
                 body
@@ -176,7 +191,8 @@ impl StorageBuilder {
             },
 
             KaramelAstType::Primative(primative) => {
-                options.storages.get_mut(storage_index).unwrap().add_constant(Rc::clone(primative));
+                let primative = options.intern_primative(primative.clone());
+                options.storages.get_mut(storage_index).unwrap().add_constant(primative);
             },
 
             KaramelAstType::List(list) => {
@@ -188,7 +204,7 @@ impl StorageBuilder {
 
             KaramelAstType::Dict(dict) => {
                 for dict_item in dict {
-                    options.storages.get_mut(storage_index).unwrap().add_constant(dict_item.key.clone());
+                    self.build(module.clone(),&dict_item.key, ast, options, storage_index)?;
                     self.build(module.clone(),&dict_item.value, ast, options, storage_index)?;
                 }
                 return Ok(())
@@ -199,8 +215,11 @@ impl StorageBuilder {
                 self.build(module.clone(),indexer, ast, options, storage_index)?;
             },
 
-            KaramelAstType::FunctionDefination { name: _, arguments: _, body } => {
-                self.build(module.clone(),body, ast, options, storage_index)?;
+            KaramelAstType::FunctionDefination { name: _, arguments: _, argument_positions: _, body, position: _ } => {
+                /* Nested definitions get their own storage, only the root definition is built here */
+                if let KaramelAstType::None = parent {
+                    self.build(module.clone(),body, ast, options, storage_index)?;
+                }
             },
 
             KaramelAstType::IfStatement {
@@ -55,18 +55,36 @@ impl KaramelPrimative {
                 true => write!(f, "doğru"),
                 false => write!(f, "yanlış")
             },
-            KaramelPrimative::List(b) => write!(f, "{:?}", b.borrow()),
-            KaramelPrimative::Dict(b) => write!(f, "{:?}", b.borrow()),
+            KaramelPrimative::List(b) => {
+                write!(f, "[")?;
+                for (index, item) in b.borrow().iter().enumerate() {
+                    if index > 0 { write!(f, ", ")?; }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            },
+            KaramelPrimative::Dict(b) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in b.borrow().iter().enumerate() {
+                    if index > 0 { write!(f, ", ")?; }
+                    write!(f, "{:?}: {}", key, value)?;
+                }
+                write!(f, "}}")
+            },
             KaramelPrimative::Text(b) => write!(f, "\"{}\"", b),
             KaramelPrimative::Function(func, _) => write!(f, "<Fonksiyon='{}'>", func.name),
             KaramelPrimative::Class(class) => write!(f, "<Sınıf='{}'>", class.get_type())
         }
     }
 
+    /// Single truthiness rule used by every conditional opcode (`Compare`,
+    /// `Not`, `And`, `Or`). Empty, `yanlış`, zero, empty texts, empty lists
+    /// and empty dictionaries are false, everything else is true. Negative
+    /// numbers and NaN are not zero, so they are true like in Python.
     pub fn is_true(&self) -> bool {
         match self {
             KaramelPrimative::Text(value)       => !value.is_empty(),
-            KaramelPrimative::Number(value)     => *value > 0.0,
+            KaramelPrimative::Number(value)     => *value != 0.0,
             KaramelPrimative::Bool(value)       => *value,
             KaramelPrimative::List(items)       => !items.borrow().is_empty(),
             KaramelPrimative::Dict(items) => !items.borrow().is_empty(),
@@ -119,18 +137,27 @@ impl From<f64> for VmObject {
     }
 }
 
-impl From<i64> for VmObject {
-    fn from(number: i64) -> Self {
+impl From<f32> for VmObject {
+    fn from(number: f32) -> Self {
         VmObject((number as f64).to_bits())
     }
 }
 
-impl From<usize> for VmObject {
-    fn from(number: usize) -> Self {
-        VmObject((number as f64).to_bits())
-    }
+/// Every number is stored as f64, so integers above 2^53 lose precision.
+macro_rules! integer_from {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for VmObject {
+                fn from(number: $type) -> Self {
+                    VmObject((number as f64).to_bits())
+                }
+            }
+        )*
+    };
 }
 
+integer_from!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
 impl From<bool> for VmObject {
     fn from(source: bool) -> Self {
         match source {
@@ -152,6 +179,12 @@ impl From<String> for VmObject {
     }
 }
 
+impl From<&str> for VmObject {
+    fn from(source: &str) -> Self {
+        VmObject::native_convert(KaramelPrimative::Text(Rc::new(source.to_string())))
+    }
+}
+
 impl From<Vec<VmObject>> for VmObject {
     fn from(source: Vec<VmObject>) -> Self {
         VmObject::native_convert(KaramelPrimative::List(RefCell::new(source)))
@@ -164,6 +197,27 @@ impl From<Rc<KaramelPrimative>> for VmObject {
     }
 }
 
+impl From<KaramelPrimative> for VmObject {
+    fn from(source: KaramelPrimative) -> Self {
+        VmObject::convert(Rc::new(source))
+    }
+}
+
+impl From<()> for VmObject {
+    fn from(_: ()) -> Self {
+        EMPTY_OBJECT
+    }
+}
+
+impl<T: Into<VmObject>> From<Option<T>> for VmObject {
+    fn from(source: Option<T>) -> Self {
+        match source {
+            Some(value) => value.into(),
+            None => EMPTY_OBJECT
+        }
+    }
+}
+
 impl From<HashMap<String, VmObject>> for VmObject {
     fn from(source: HashMap<String, VmObject>) -> Self {
         VmObject::convert(Rc::new(KaramelPrimative::Dict(RefCell::new(source))))
@@ -182,9 +236,19 @@ impl fmt::Display for KaramelPrimative {
     }
 }
 
+/// Shows the type and the value instead of the nan-boxed bits, `VmObject(Number(3.0))`.
 impl fmt::Debug for VmObject {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", &*self.deref())
+        match &*self.deref() {
+            KaramelPrimative::Empty => write!(f, "VmObject(Empty)"),
+            KaramelPrimative::Number(number) => write!(f, "VmObject(Number({:?}))", number),
+            KaramelPrimative::Bool(value) => write!(f, "VmObject(Bool({:?}))", value),
+            KaramelPrimative::Text(text) => write!(f, "VmObject(Text({:?}))", text),
+            KaramelPrimative::List(items) => write!(f, "VmObject(List({:?}))", items.borrow()),
+            KaramelPrimative::Dict(items) => write!(f, "VmObject(Dict({:?}))", items.borrow()),
+            KaramelPrimative::Function(function, _) => write!(f, "VmObject(Function({:?}))", function.name),
+            KaramelPrimative::Class(class) => write!(f, "VmObject(Class({:?}))", class.get_type())
+        }
     }
 }
 
@@ -205,8 +269,9 @@ impl PartialEq for KaramelPrimative {
         match (self, &other) {
             (KaramelPrimative::Bool(lvalue),            KaramelPrimative::Bool(rvalue)) => lvalue == rvalue,
             (KaramelPrimative::Empty,                   KaramelPrimative::Empty)        => true,
-            (KaramelPrimative::Number(n),               KaramelPrimative::Number(m))    => if n.is_nan() && m.is_nan() { true } else { n == m },
-            (KaramelPrimative::Text(lvalue),            KaramelPrimative::Text(rvalue)) => lvalue == rvalue,
+            /* IEEE rules, NaN is not equal to anything including itself */
+            (KaramelPrimative::Number(n),               KaramelPrimative::Number(m))    => n == m,
+            (KaramelPrimative::Text(lvalue),            KaramelPrimative::Text(rvalue)) => Rc::ptr_eq(lvalue, rvalue) || lvalue == rvalue,
             (KaramelPrimative::List(l_value),           KaramelPrimative::List(r_value))       => {
                 if (*l_value).borrow().len() != (*r_value).borrow().len() {
                     return false;
@@ -342,4 +407,101 @@ impl VmObject {
             false => None
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use super::*;
+
+    macro_rules! from_test {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name () {
+                let object = VmObject::from($source);
+                assert_eq!(*object.deref(), $expected);
+                assert_eq!(*VmObject::convert(object.deref()).deref(), $expected);
+            }
+        };
+    }
+
+    from_test!{from_f64, 1.5_f64, KaramelPrimative::Number(1.5)}
+    from_test!{from_f32, 1.5_f32, KaramelPrimative::Number(1.5)}
+    from_test!{from_i8, -8_i8, KaramelPrimative::Number(-8.0)}
+    from_test!{from_i16, -16_i16, KaramelPrimative::Number(-16.0)}
+    from_test!{from_i32, -32_i32, KaramelPrimative::Number(-32.0)}
+    from_test!{from_i64, -64_i64, KaramelPrimative::Number(-64.0)}
+    from_test!{from_isize, -1_isize, KaramelPrimative::Number(-1.0)}
+    from_test!{from_u8, 8_u8, KaramelPrimative::Number(8.0)}
+    from_test!{from_u16, 16_u16, KaramelPrimative::Number(16.0)}
+    from_test!{from_u32, 32_u32, KaramelPrimative::Number(32.0)}
+    from_test!{from_u64, 64_u64, KaramelPrimative::Number(64.0)}
+    from_test!{from_usize, 1024_usize, KaramelPrimative::Number(1024.0)}
+    from_test!{from_bool_1, true, KaramelPrimative::Bool(true)}
+    from_test!{from_bool_2, false, KaramelPrimative::Bool(false)}
+    from_test!{from_str, "karamel", KaramelPrimative::Text(Rc::new("karamel".to_string()))}
+    from_test!{from_string, "karamel".to_string(), KaramelPrimative::Text(Rc::new("karamel".to_string()))}
+    from_test!{from_rc_string, Rc::new("karamel".to_string()), KaramelPrimative::Text(Rc::new("karamel".to_string()))}
+    from_test!{from_unit, (), KaramelPrimative::Empty}
+    from_test!{from_option_1, Some(10_i32), KaramelPrimative::Number(10.0)}
+    from_test!{from_option_2, None::<i32>, KaramelPrimative::Empty}
+    from_test!{from_primative, KaramelPrimative::Number(3.0), KaramelPrimative::Number(3.0)}
+    from_test!{from_rc_primative, Rc::new(KaramelPrimative::Text(Rc::new("a".to_string()))), KaramelPrimative::Text(Rc::new("a".to_string()))}
+    from_test!{from_list, vec![VmObject::from(1), VmObject::from("a")], KaramelPrimative::List(RefCell::new(vec![VmObject::from(1), VmObject::from("a")]))}
+
+    #[test]
+    fn from_dict() {
+        let mut items = HashMap::new();
+        items.insert("ad".to_string(), VmObject::from("erhan"));
+
+        let object = VmObject::from(items.clone());
+        assert_eq!(*object.deref(), KaramelPrimative::Dict(RefCell::new(items)));
+    }
+
+    #[test]
+    fn debug_format() {
+        assert_eq!(format!("{:?}", VmObject::from(3.0)), "VmObject(Number(3.0))");
+        assert_eq!(format!("{:?}", VmObject::from(-1.5)), "VmObject(Number(-1.5))");
+        assert_eq!(format!("{:?}", VmObject::from(true)), "VmObject(Bool(true))");
+        assert_eq!(format!("{:?}", VmObject::from(())), "VmObject(Empty)");
+        assert_eq!(format!("{:?}", VmObject::from("karamel")), "VmObject(Text(\"karamel\"))");
+        assert_eq!(format!("{:?}", VmObject::from(vec![VmObject::from(1.0), VmObject::from("a")])), "VmObject(List([VmObject(Number(1.0)), VmObject(Text(\"a\"))]))");
+
+        let mut dict = HashMap::new();
+        dict.insert("a".to_string(), VmObject::from(false));
+        assert_eq!(format!("{:?}", VmObject::from(dict)), "VmObject(Dict({\"a\": VmObject(Bool(false))}))");
+    }
+
+    #[test]
+    fn display_format() {
+        assert_eq!(format!("{}", VmObject::from(vec![VmObject::from(1.0), VmObject::from("a")])), "[1, \"a\"]");
+        assert_eq!(format!("{}", VmObject::from(Vec::new())), "[]");
+
+        let mut dict = HashMap::new();
+        dict.insert("a".to_string(), VmObject::from(vec![VmObject::from(2.5)]));
+        assert_eq!(format!("{}", VmObject::from(dict)), "{\"a\": [2.5]}");
+        assert_eq!(format!("{}", VmObject::from(HashMap::new())), "{}");
+    }
+
+    #[test]
+    fn nan_equality() {
+        let nan = KaramelPrimative::Number(f64::NAN);
+        assert!(nan != KaramelPrimative::Number(f64::NAN));
+        assert!(nan != nan);
+        assert!(nan != KaramelPrimative::Number(0.0));
+        assert!(KaramelPrimative::List(RefCell::new(vec![VmObject::from(f64::NAN)])) != KaramelPrimative::List(RefCell::new(vec![VmObject::from(f64::NAN)])));
+        assert!(KaramelPrimative::Number(1.5) == KaramelPrimative::Number(1.5));
+    }
+
+    #[test]
+    fn number_is_true() {
+        assert_eq!(KaramelPrimative::Number(0.0).is_true(), false);
+        assert_eq!(KaramelPrimative::Number(-0.0).is_true(), false);
+        assert_eq!(KaramelPrimative::Number(1.0).is_true(), true);
+        assert_eq!(KaramelPrimative::Number(-1.0).is_true(), true);
+        assert_eq!(KaramelPrimative::Number(0.5).is_true(), true);
+        assert_eq!(KaramelPrimative::Number(f64::NAN).is_true(), true);
+    }
+}
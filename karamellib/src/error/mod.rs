@@ -1,5 +1,6 @@
 use std::borrow::Borrow;
 use std::rc::Rc;
+use std::ops::Range;
 
 use strum::EnumMessage;
 use strum_macros::EnumIter;
@@ -10,12 +11,54 @@ use thiserror::Error;
 use crate::compiler::KaramelPrimative;
 
 
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
 pub enum KaramelErrorSeverity {
     Minor,
     Major,
     Critical,
     Fatal
 }
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Error)]
+pub enum KaramelDiagnosticType {
+    #[error("'{0}' değişkeni tanımlandı fakat kullanılmadı")]
+    UnusedVariable(String),
+
+    #[error("'{1}' fonksiyonundaki '{0}' değişkeni üst kapsamdaki aynı isimli değişkeni gizliyor")]
+    ShadowedVariable(String, String)
+}
+
+/// Non-fatal finding collected during compilation. Compilation continues
+/// and all diagnostics are available on the context afterwards.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct KaramelDiagnostic {
+    pub severity: KaramelErrorSeverity,
+    pub diagnostic_type: KaramelDiagnosticType,
+    pub line: u32,
+    pub column: u32
+}
+
+impl KaramelDiagnostic {
+    pub fn warning(diagnostic_type: KaramelDiagnosticType, line: u32, column: u32) -> Self {
+        KaramelDiagnostic {
+            severity: KaramelErrorSeverity::Minor,
+            diagnostic_type,
+            line,
+            column
+        }
+    }
+
+    pub fn message(&self) -> String {
+        self.diagnostic_type.to_string()
+    }
+}
 #[derive(Clone)]
 #[derive(Debug)]
 #[derive(PartialEq)]
@@ -254,7 +297,85 @@ pub enum KaramelErrorType {
 
     #[error("Öperatör geçerli değil")]
     #[strum(message = "153")]
-    OperatorNotValid
+    OperatorNotValid,
+
+    #[error("'{pattern}' deseni geçerli değil. Hata: {error}")]
+    #[strum(message = "154")]
+    PatternNotValid {
+        pattern: String,
+        error: String
+    },
+
+    #[error("'{0}' fonksiyonu boş liste ile kullanılamaz")]
+    #[strum(message = "155")]
+    ListIsEmpty(String),
+
+    #[error("'{function}' fonksiyonu liste elemanlarının {expected} olmasını bekliyor")]
+    #[strum(message = "156")]
+    ListElementTypeNotValid {
+        function: String,
+        expected: String
+    },
+
+    #[error("'içinde' sözcüğü eksik")]
+    #[strum(message = "157")]
+    MissingIn,
+
+    #[error("'her' döngüsü için değişken ismi eksik")]
+    #[strum(message = "158")]
+    MissingLoopVariable,
+
+    #[error("'{0}' modülü yüklenemez, modül yükleme izni yok")]
+    #[strum(message = "159")]
+    ModuleLoadingNotAllowed(String),
+
+    #[error("En fazla {0} fonksiyon çağrısı iç içe kullanılabilir")]
+    #[strum(message = "160")]
+    CallDepthExceeded(usize),
+
+    #[error("Program {0} komut sınırını aştı")]
+    #[strum(message = "161")]
+    InstructionLimitExceeded(usize),
+
+    #[error("'{0}' bir fonksiyon, değer atanamaz")]
+    #[strum(message = "162")]
+    CannotAssignToFunction(String),
+
+    #[error("Modül adı boş olamaz")]
+    #[strum(message = "163")]
+    EmptyModuleName,
+
+    #[error("'{0}' fonksiyonu için yığında yeterli alan yok")]
+    #[strum(message = "164")]
+    StackOverflow(String),
+
+    #[error("Beklenmeyen virgül")]
+    #[strum(message = "165")]
+    UnexpectedComma,
+
+    #[error("'{0}' fonksiyonunda adım sıfır olamaz")]
+    #[strum(message = "166")]
+    StepCannotBeZero(String),
+
+    #[error("Girintilemede boşluk ve sekme birlikte kullanılamaz")]
+    #[strum(message = "167")]
+    MixedIndentation,
+
+    #[error("Sayı sınırların dışında")]
+    #[strum(message = "168")]
+    NumberOutOfRange,
+
+    #[error("'*' ile açılan değer en fazla 255 elemanlı bir liste olmalıdır")]
+    #[strum(message = "169")]
+    SpreadArgumentNotValid,
+
+    #[error("'{0}' ile fonksiyon tanımlanamaz, 'fonk' kullanılmalı")]
+    #[strum(message = "170")]
+    FunctionKeywordNotValid(String),
+
+    #[error("'{0}' fonksiyonunda karışık tür sıralanamaz")]
+    #[strum(message = "171")]
+    MixedListCannotBeSorted(String)
 }
 
 impl From<KaramelErrorType> for KaramelError {
@@ -262,6 +383,8 @@ impl From<KaramelErrorType> for KaramelError {
         KaramelError {
             column: 0,
             line: 0,
+            span: None,
+            backtrace: Vec::new(),
             error_type: item
         }
     }
@@ -275,24 +398,96 @@ impl From<KaramelError> for KaramelErrorType {
 
 #[derive(Clone)]
 #[derive(Debug)]
-#[derive(PartialEq)]
 pub struct KaramelError {
     pub error_type: KaramelErrorType,
     pub line: u32,
+    pub column: u32,
+
+    /// Byte range of the offending token in the source, if it is known.
+    pub span: Option<Range<usize>>,
+
+    /// Functions that were running when the error occurred, innermost
+    /// first. Empty for compile errors and top level code.
+    pub backtrace: Vec<KaramelStackFrame>
+}
+
+/// Function in the backtrace of a runtime error. Line and column are the
+/// location of the call in the caller, zero when it is not known.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct KaramelStackFrame {
+    pub function: String,
+    pub line: u32,
     pub column: u32
 }
 
 impl KaramelError {
     pub fn new(line: u32, column: u32, error_type: KaramelErrorType) -> Self {
-        KaramelError { line, column, error_type }
+        KaramelError { line, column, error_type, span: None, backtrace: Vec::new() }
+    }
+
+    pub fn with_span(line: u32, column: u32, span: Range<usize>, error_type: KaramelErrorType) -> Self {
+        KaramelError { line, column, error_type, span: Some(span), backtrace: Vec::new() }
+    }
+
+    pub fn with_backtrace(mut self, backtrace: Vec<KaramelStackFrame>) -> Self {
+        self.backtrace = backtrace;
+        self
+    }
+}
+
+/* Span comes from the same token as the line and column, it is not compared. Backtrace is not compared either */
+impl PartialEq for KaramelError {
+    fn eq(&self, other: &Self) -> bool {
+        self.error_type == other.error_type && self.line == other.line && self.column == other.column
     }
 }
 
 pub fn generate_error_message<T: AsRef<str>, E: Borrow<KaramelError>>(data: T, error: E) -> String {
     let error_ref = error.borrow();
-    let lines = data.as_ref().split(|c| c == '\n').collect::<Vec<_>>();
-    let line = lines.iter().skip(error_ref.line as usize).next().unwrap();
-    return format!("...\r\n{}\r\n{:>width$} [{}:{}] (#{}) {}", line, "^", error_ref.line, error_ref.column, error_ref.error_type.get_message().unwrap(), error_ref.error_type,  width=error_ref.column as usize);
+    let data = data.as_ref().replace("\r\n", "\n");
+    let line = data.split(|c| c == '\n' || c == '\r').nth(error_ref.line as usize).unwrap_or("");
+    return format!("...\r\n{}\r\n{}^ [{}:{}] (#{}) {}", line, caret_padding(line, error_ref.column), error_ref.line, error_ref.column, error_ref.error_type.get_message().unwrap(), error_ref.error_type);
+}
+
+/// Renders every error with its source line, ordered by position.
+pub fn generate_error_report<T: AsRef<str>>(data: T, errors: &[KaramelError]) -> String {
+    let mut errors = errors.iter().collect::<Vec<_>>();
+    errors.sort_by_key(|error| (error.line, error.column));
+    errors.iter()
+        .map(|error| generate_error_message(data.as_ref(), *error))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// Renders the call stack of a runtime error, one line per function.
+/// Callers are shown with the location of the call made from them.
+pub fn generate_backtrace(error: &KaramelError) -> String {
+    error.backtrace.iter()
+        .enumerate()
+        .map(|(index, frame)| match index {
+            0 => format!("'{}' içinde", frame.function),
+            _ => {
+                let call = &error.backtrace[index - 1];
+                format!("'{}' tarafından çağrıldı [{}:{}]", frame.function, call.line, call.column)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// Column is counted in characters, not bytes. Caret is placed under the
+/// character before the column, tabs are kept so the caret stays aligned.
+fn caret_padding(line: &str, column: u32) -> String {
+    line.chars()
+        .chain(std::iter::repeat(' '))
+        .take(column.saturating_sub(1) as usize)
+        .map(|ch| match ch {
+            '\t' => '\t',
+            _ => ' '
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -320,7 +515,9 @@ mod test {
         let error_info = super::KaramelError {
             error_type: super::KaramelErrorType::SyntaxError,
              line: 0,
-             column: 0
+             column: 0,
+             span: None,
+             backtrace: Vec::new()
          };
         let error_message = super::generate_error_message("merhaba dunya", &error_info);
 
@@ -331,4 +528,34 @@ mod test {
         let error_message = format!("{}", error_info.error_type);
         assert!(error_message.contains(&error_message), "Mesaj icerisinde hata kodu mesaji");
     }
+
+    #[test]
+    fn test_error_message_caret() {
+        let error_info = super::KaramelError::new(1, 11, super::KaramelErrorType::SyntaxError);
+        let error_message = super::generate_error_message("sayı = 1\r\nçıktı = ğüş +\r\n", &error_info);
+        let lines = error_message.split("\r\n").collect::<Vec<_>>();
+
+        assert_eq!(lines[1], "çıktı = ğüş +");
+        assert!(lines[2].starts_with("          ^ [1:11]"));
+        assert_eq!(lines[1].chars().nth(lines[2].chars().position(|ch| ch == '^').unwrap()), Some('ş'));
+
+        let error_info = super::KaramelError::new(0, 3, super::KaramelErrorType::SyntaxError);
+        let error_message = super::generate_error_message("\tğ +", &error_info);
+        assert!(error_message.contains("\r\n\t ^ [0:3]"));
+    }
+
+    #[test]
+    fn test_error_report() {
+        let errors = [
+            super::KaramelError::new(2, 5, super::KaramelErrorType::ColonMarkMissing),
+            super::KaramelError::new(0, 3, super::KaramelErrorType::SyntaxError)
+        ];
+        let report = super::generate_error_report("a = +\nb = 1\nc ise\n", &errors);
+
+        let first = report.find("a = +").unwrap();
+        let second = report.find("c ise").unwrap();
+        assert!(first < second);
+        assert!(report.find("[0:3]").unwrap() < report.find("[2:5]").unwrap());
+        assert!(!report.contains("b = 1"));
+    }
 }
\ No newline at end of file
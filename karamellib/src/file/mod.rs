@@ -4,7 +4,7 @@ use std::path::Path;
 use std::fs::canonicalize;
 
 use crate::compiler::KaramelCompilerContext;
-use crate::constants::{KARAMEL_FILE_EXTENSION, STARTUP_MODULE_NAME};
+use crate::constants::STARTUP_MODULE_NAME;
 use crate::error::KaramelErrorType;
 
 pub fn read_file<T: Borrow<str>>(file_name: T) -> Result<String, KaramelErrorType> {
@@ -44,9 +44,10 @@ fn read_script<T: Borrow<str>>(file_name: T, context: &KaramelCompilerContext) -
 }
 
 pub fn read_module_or_script<T: Borrow<str>>(file_name: T, context: &KaramelCompilerContext) -> Result<String, KaramelErrorType> {
-    let computed_file_name = match file_name.borrow().ends_with(KARAMEL_FILE_EXTENSION) {
+    let extension = &context.config.file_extension;
+    let computed_file_name = match file_name.borrow().ends_with(&extension[..]) {
         true => file_name.borrow().to_string(),
-        false => format!("{}{}", file_name.borrow(), KARAMEL_FILE_EXTENSION)
+        false => format!("{}{}", file_name.borrow(), extension)
     };
 
     match read_script(computed_file_name, context) {
@@ -39,6 +39,17 @@ macro_rules! pop {
     }}
 }
 
+/// Same as `pop!` but the value is not wrapped with a new `Rc`, numbers, bools and empty do not allocate.
+#[macro_export] 
+macro_rules! pop_clean {
+    ($context: expr, $message: expr) => {{
+        pop_raw!($context, $message).deref_clean()
+    }}
+}
+
+/// Returns a copy of the top value, the slot is not cleared and is overwritten by the next push.
+/// Boxed values of the copy and the slot point to the same allocation, so the result of an opcode
+/// needs to be built before it is written to the slot of the popped value.
 #[macro_export] 
 macro_rules! pop_raw {
     ($context: expr, $message: expr) => {{
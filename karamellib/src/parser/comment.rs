@@ -20,7 +20,8 @@ impl TokenParser for CommentParser {
             while !tokinizer.is_end() && !comment_end {
                 tokinizer.increase_index();
 
-                if ch.is_new_line() {
+                /* Line is increased with the '\n' of the '\r\n' */
+                if ch.is_new_line() && !(ch == '\r' && tokinizer.get_char() == '\n') {
                     tokinizer.increate_line();
                 }
 
@@ -43,13 +44,8 @@ impl TokenParser for CommentParser {
             tokinizer.increase_index();
             ch = tokinizer.get_char();
 
-            while !tokinizer.is_end() &&  ch != '\n' {
+            while !tokinizer.is_end() && !ch.is_new_line() {
                 tokinizer.increase_index();
-
-                if ch.is_new_line() {
-                    tokinizer.increate_line();
-                }
-
                 ch = tokinizer.get_char();
             }
         }
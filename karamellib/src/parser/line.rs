@@ -10,22 +10,49 @@ impl TokenParser for LineParser {
     }
 
     fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType> {
+        let start_column = tokinizer.column;
+
+        /* '\r\n' is a single line break */
+        if tokinizer.get_char() == '\r' && tokinizer.get_next_char() == '\n' {
+            tokinizer.increase_index();
+        }
         tokinizer.increase_index();
 
+        let end_column = tokinizer.column;
+        let whitespace_type = KaramelWhitespaceType::from_char(tokinizer.get_char()).unwrap_or(KaramelWhitespaceType::Space);
         let mut whitespace_count: u32 = 0;
-        let start_column = tokinizer.column;
-        let mut ch                   = tokinizer.get_char();
-
-        while !tokinizer.is_end() && ch == ' ' {
+        while !tokinizer.is_end() && KaramelWhitespaceType::from_char(tokinizer.get_char()) == Some(whitespace_type) {
             tokinizer.increase_index();
             whitespace_count += 1;
-            ch = tokinizer.get_char();
         }
 
+        /* Token is positioned at the line break, the span still covers the indentation of the next line */
+        tokinizer.column = end_column;
+        let indentation = whitespace_count.min(u8::MAX as u32) as u8;
+
+        /* Expression continues on the next line */
+        match tokinizer.bracket_depth {
+            0 => tokinizer.add_token(start_column, KaramelTokenType::NewLine(indentation, whitespace_type)),
+            _ => tokinizer.add_token(start_column, KaramelTokenType::WhiteSpace(indentation, whitespace_type))
+        };
+
         tokinizer.increate_line();
-        tokinizer.add_token(start_column, KaramelTokenType::NewLine(whitespace_count as u8));
         tokinizer.column = whitespace_count;
 
+        /* Indentation of the lines should use only one type of the whitespace, empty lines are not checked */
+        let ch = tokinizer.get_char();
+        if tokinizer.bracket_depth == 0 && whitespace_count > 0 && !tokinizer.is_end() && !ch.is_new_line() {
+            if KaramelWhitespaceType::from_char(ch).is_some() {
+                return Err(KaramelErrorType::MixedIndentation);
+            }
+
+            match tokinizer.indentation_type {
+                Some(indentation_type) if indentation_type != whitespace_type => return Err(KaramelErrorType::MixedIndentation),
+                Some(_) => (),
+                None => tokinizer.indentation_type = Some(whitespace_type)
+            };
+        }
+
         return Ok(());
     }
 }
\ No newline at end of file
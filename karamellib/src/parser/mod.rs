@@ -7,7 +7,6 @@ mod whitespace;
 mod comment;
 
 use std::str;
-use std::collections::HashMap;
 
 use crate::{error::KaramelError, types::*};
 use self::number::NumberParser;
@@ -34,7 +33,10 @@ impl<'a> Parser<'a> {
                 iter_second: data.chars().peekable(),
                 iter_third: data.chars().peekable(),
                 data: data.to_string(),
-                index: 0
+                index: 0,
+                token_index: 0,
+                bracket_depth: 0,
+                indentation_type: None
             }
         };
 
@@ -44,8 +46,8 @@ impl<'a> Parser<'a> {
         return parser;
     }
 
-    pub fn tokens(&self) -> Vec<Token> {
-        self.tokinizer.tokens.to_vec()
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokinizer.tokens
     }
 
     pub fn parse(&mut self) -> ParseResult {
@@ -57,11 +59,7 @@ impl<'a> Parser<'a> {
         let text_parser_single  = TextParser       { tag:'\'' };
         let text_parser_double  = TextParser       { tag:'"' };
         let operator_parser     = OperatorParser   {};
-        let mut symbol_parser   = SymbolParser     {
-            keywords: HashMap::new()
-        };
-
-        symbol_parser.init_parser();
+        let symbol_parser       = SymbolParser     {};
 
         while self.tokinizer.is_end() == false {
             let status: Result<(), KaramelErrorType>;
@@ -95,9 +93,13 @@ impl<'a> Parser<'a> {
                 return Err(KaramelError {
                     error_type: status.err().unwrap(),
                     line: self.tokinizer.line,
-                    column: self.tokinizer.column
+                    column: self.tokinizer.column,
+                    span: Some(self.tokinizer.current_span()),
+                    backtrace: Vec::new()
                 });
             }
+
+            self.tokinizer.token_index = self.tokinizer.index;
         }
 
         Ok(())
@@ -9,23 +9,19 @@ impl NumberParser {
         tokinizer.get_char()
     }
 
-    fn get_digits(&self, tokinizer: &mut Tokinizer) -> (u8, u64) {
-        let mut number: u64    = 0;
-        let mut num_count: u8  = 0;
-        let mut ch :char       = tokinizer.get_char();
+    fn get_digits(&self, tokinizer: &mut Tokinizer) -> String {
+        let mut digits = String::new();
+        let mut ch     = tokinizer.get_char();
 
         while !tokinizer.is_end() && (ch.is_ascii_digit() || ch == '_') {
             if ch != '_' {
-                num_count += 1;
-
-                number *= u64::pow(10, 1);
-                number += ch as u64 - '0' as u64;
+                digits.push(ch);
             }
 
             ch = self.increase(tokinizer);
         }
 
-        (num_count, number)
+        digits
     }
 
     fn detect_number_system(&self, tokinizer: &mut Tokinizer) -> KaramelNumberSystem {
@@ -52,78 +48,79 @@ impl NumberParser {
         return KaramelNumberSystem::Decimal;
     }
 
-    fn parse_hex(&self, tokinizer: &mut Tokinizer) -> KaramelTokenType {
-        let mut number :u64 = 0;
-        let mut ch :char    = tokinizer.get_char();
+    fn parse_hex(&self, tokinizer: &mut Tokinizer) -> Result<KaramelTokenType, KaramelErrorType> {
+        let mut number :Option<i64> = Some(0);
+        let mut ch :char            = tokinizer.get_char();
 
+        /* All digits are consumed even if the number is out of range */
         while !tokinizer.is_end() && ch.is_ascii_hexdigit() {
-            number = number << 4;
-
-            let tmp_ch = ch.to_digit(16);
-            if tmp_ch.is_some() {
-                number += tmp_ch.unwrap() as u64;
-            }
+            number = number
+                .and_then(|number| number.checked_mul(16))
+                .and_then(|number| number.checked_add(ch.to_digit(16).unwrap_or(0) as i64));
 
             ch = self.increase(tokinizer);
         }
 
-        KaramelTokenType::Integer(number as i64)
+        match number {
+            Some(number) => Ok(KaramelTokenType::Integer(number, KaramelNumberSystem::Hexadecimal)),
+            None         => Err(KaramelErrorType::NumberOutOfRange)
+        }
     }
 
-    fn parse_octal(&self, tokinizer: &mut Tokinizer) -> KaramelTokenType {
-        let mut number :u64 = 0;
-        let mut ch :char    = tokinizer.get_char();
+    fn parse_octal(&self, tokinizer: &mut Tokinizer) -> Result<KaramelTokenType, KaramelErrorType> {
+        let mut number :Option<i64> = Some(0);
+        let mut ch :char            = tokinizer.get_char();
 
-        while !tokinizer.is_end() && ch >= '0' && ch <= '7' {
-            number = number << 3;
-
-            let tmp_ch = ch.to_digit(8);
-            if tmp_ch.is_some() {
-                number += tmp_ch.unwrap() as u64;
-            }
+        /* All digits are consumed even if the number is out of range */
+        while !tokinizer.is_end() && matches!(ch, '0'..='7') {
+            number = number
+                .and_then(|number| number.checked_mul(8))
+                .and_then(|number| number.checked_add(ch.to_digit(8).unwrap_or(0) as i64));
 
             ch = self.increase(tokinizer);
         }
 
-        KaramelTokenType::Integer(number as i64)
+        match number {
+            Some(number) => Ok(KaramelTokenType::Integer(number, KaramelNumberSystem::Octal)),
+            None         => Err(KaramelErrorType::NumberOutOfRange)
+        }
     }
 
-    fn parse_binary(&self, tokinizer: &mut Tokinizer) -> KaramelTokenType {
-        let mut number :u64 = 0;
-        let mut ch :char    = tokinizer.get_char();
-
-        while !tokinizer.is_end() && ch >= '0' && ch <= '1' {
-            number = number << 1;
+    fn parse_binary(&self, tokinizer: &mut Tokinizer) -> Result<KaramelTokenType, KaramelErrorType> {
+        let mut number :Option<i64> = Some(0);
+        let mut ch :char            = tokinizer.get_char();
 
-            let tmp_ch = ch.to_digit(2);
-            if tmp_ch.is_some() {
-                number += tmp_ch.unwrap() as u64;
-            }
+        /* All digits are consumed even if the number is out of range */
+        while !tokinizer.is_end() && matches!(ch, '0'..='1') {
+            number = number
+                .and_then(|number| number.checked_mul(2))
+                .and_then(|number| number.checked_add(ch.to_digit(2).unwrap_or(0) as i64));
 
             ch = self.increase(tokinizer);
         }
 
-        KaramelTokenType::Integer(number as i64)
+        match number {
+            Some(number) => Ok(KaramelTokenType::Integer(number, KaramelNumberSystem::Binary)),
+            None         => Err(KaramelErrorType::NumberOutOfRange)
+        }
     }
 
-    fn parse_decimal(&self, tokinizer: &mut Tokinizer) -> KaramelTokenType {
+    fn parse_decimal(&self, tokinizer: &mut Tokinizer) -> Result<KaramelTokenType, KaramelErrorType> {
         /*
         [NUMBER](.[NUMBER](E(-+)[NUMBER]))
         */
 
-        let (_, digits)  = self.get_digits(tokinizer);
-        let before_comma = digits;
+        let before_comma = self.get_digits(tokinizer);
         let mut ch       = tokinizer.get_char();
         let ch_next = tokinizer.get_next_char();
 
         /* Double number */
-        if !tokinizer.is_end() && ch == '.' && (ch_next >= '0' && ch_next <= '9') {
+        if !tokinizer.is_end() && ch == '.' && ch_next.is_ascii_digit() {
             self.increase(tokinizer);
 
-            let (digit_num, digits) = self.get_digits(tokinizer);
-            let after_comma = digits;
-            let dot_place   = digit_num;
-            ch          = tokinizer.get_char();
+            let after_comma = self.get_digits(tokinizer);
+            let mut number  = format!("0{}.{}", before_comma, after_comma);
+            ch              = tokinizer.get_char();
 
             if !tokinizer.is_end() && (ch == 'e' || ch == 'E') {
                 let mut is_minus      = false;
@@ -142,23 +139,26 @@ impl NumberParser {
                     }
                 }
 
-                let (_, digits) = self.get_digits(tokinizer);
-                let e_after    = digits;
-                self.increase(tokinizer);
+                let e_after = self.get_digits(tokinizer);
 
-                let num = before_comma as f64 + (after_comma as f64 * f64::powi(10.0, -1 * dot_place as i32));
-
-                return match is_minus {
-                    true  => KaramelTokenType::Double(num / f64::powi(10.0, e_after as i32)),
-                    false => KaramelTokenType::Double(num * f64::powi(10.0, e_after as i32))
+                number.push('e');
+                if is_minus {
+                    number.push('-');
                 }
+                number.push_str(if e_after.is_empty() { "0" } else { &e_after });
             }
 
-            let num = before_comma as f64 + (after_comma as f64 * f64::powi(10.0, -1 * dot_place as i32));
-            return KaramelTokenType::Double(num)
+            /* Too big numbers are parsed as infinity */
+            return match number.parse::<f64>() {
+                Ok(number) if number.is_finite() => Ok(KaramelTokenType::Double(number)),
+                _ => Err(KaramelErrorType::NumberOutOfRange)
+            };
         }
 
-        KaramelTokenType::Integer(before_comma as i64)
+        match before_comma.parse::<i64>() {
+            Ok(number) => Ok(KaramelTokenType::Integer(number, KaramelNumberSystem::Decimal)),
+            Err(_)     => Err(KaramelErrorType::NumberOutOfRange)
+        }
     }
 }
 
@@ -166,7 +166,7 @@ impl TokenParser for NumberParser {
     fn check(&self, tokinizer: &mut Tokinizer) -> bool {
         let ch = tokinizer.get_char();
         let ch_next = tokinizer.get_next_char();
-        (ch == '.' && (ch_next >= '0' && ch_next <= '9')) || (ch >= '0' && ch <= '9')
+        (ch == '.' && ch_next.is_ascii_digit()) || ch.is_ascii_digit()
     }
 
     fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType> {
@@ -179,6 +179,14 @@ impl TokenParser for NumberParser {
             KaramelNumberSystem::Decimal     => self.parse_decimal(tokinizer),
             KaramelNumberSystem::Hexadecimal => self.parse_hex(tokinizer)
         };
+
+        let token_type = match token_type {
+            Ok(token_type) => token_type,
+            Err(error) => {
+                tokinizer.column = start_column;
+                return Err(error);
+            }
+        };
         tokinizer.add_token(start_column, token_type);
         
         if tokinizer.get_char().is_alphabetic() && !tokinizer.get_char().is_whitespace() {
@@ -27,6 +27,7 @@ impl TokenParser for OperatorParser {
             ('<', '=') => KaramelOperatorType::LessEqualThan,
             ('>', '=') => KaramelOperatorType::GreaterEqualThan,
             ('*', '=') => KaramelOperatorType::AssignMultiplication,
+            ('*', '*') => KaramelOperatorType::Exponent,
             ('*', '/') => KaramelOperatorType::CommentMultilineEnd,
             ('=', '=') => KaramelOperatorType::Equal,
             _ =>  KaramelOperatorType::None
@@ -60,15 +61,24 @@ impl TokenParser for OperatorParser {
             };
         }
 
-        if ch == '\r' {
-            return Ok(());
-        }
-
         if operator_type == KaramelOperatorType::None {
-            log::debug!("'{}' not found", ch as usize);
+            log::debug!("'{}' not found", ch);
+
+            /* Error is reported at the start of the invalid character */
+            tokinizer.column = start;
             return Err(KaramelErrorType::CharNotValid);
         }
         
+        match operator_type {
+            KaramelOperatorType::LeftParentheses |
+            KaramelOperatorType::SquareBracketStart |
+            KaramelOperatorType::CurveBracketStart => tokinizer.bracket_depth += 1,
+            KaramelOperatorType::RightParentheses |
+            KaramelOperatorType::SquareBracketEnd |
+            KaramelOperatorType::CurveBracketEnd => tokinizer.bracket_depth = tokinizer.bracket_depth.saturating_sub(1),
+            _ => ()
+        };
+
         tokinizer.add_token(start, KaramelTokenType::Operator(operator_type));
         return Ok(());
     }
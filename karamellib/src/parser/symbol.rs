@@ -1,19 +1,8 @@
-use std::collections::HashMap;
 use std::rc::Rc;
 use crate::types::*;
 use crate::error::KaramelErrorType;
 
-pub struct SymbolParser {
-    pub keywords: HashMap<&'static str, KaramelKeywordType>
-}
-
-impl SymbolParser {
-    pub fn init_parser(&mut self) {
-        for (keyword, keyword_enum) in KEYWORDS.iter() {
-            self.keywords.insert(keyword, *keyword_enum);
-        }
-    }
-}
+pub struct SymbolParser;
 
 impl TokenParser for SymbolParser {
     fn check(&self, tokinizer: &mut Tokinizer) -> bool {
@@ -40,15 +29,10 @@ impl TokenParser for SymbolParser {
             end += ch.len_utf8();
             tokinizer.increase_index();
         }
-        if self.keywords.contains_key(&tokinizer.data[start..end]) {
-            let keyword = match self.keywords.get(&tokinizer.data[start..end]) {
-                Some(keyword) => keyword,
-                None => &KaramelKeywordType::None
-            };
-
+        if let Some(keyword) = KEYWORD_MAP.get(&tokinizer.data[start..end]) {
             let token_type = match keyword.to_operator() {
-                KaramelOperatorType::None => KaramelTokenType::Keyword(*keyword),
-                _                       => KaramelTokenType::Operator(keyword.to_operator())
+                Some(operator) => KaramelTokenType::Operator(operator),
+                None           => KaramelTokenType::Keyword(*keyword)
             };
             tokinizer.add_token(start_column as u32, token_type);
             return Ok(());
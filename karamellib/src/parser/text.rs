@@ -65,7 +65,10 @@ fn text_parse_test_1() {
         iter_second: data.chars().peekable(),
         iter_third: data.chars().peekable(),
         data: data.to_string(),
-        index: 0
+        index: 0,
+        token_index: 0,
+        bracket_depth: 0,
+        indentation_type: None
     };
 
     let parser = TextParser { tag: '"' };
@@ -97,7 +100,10 @@ fn text_parse_test_2() {
         iter_second: data.chars().peekable(),
         iter_third: data.chars().peekable(),
         data: data.to_string(),
-        index: 0
+        index: 0,
+        token_index: 0,
+        bracket_depth: 0,
+        indentation_type: None
     };
 
     let parser = TextParser { tag: '\'' };
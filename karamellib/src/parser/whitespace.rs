@@ -6,7 +6,7 @@ pub struct WhitespaceParser;
 impl TokenParser for WhitespaceParser {
     fn check(&self, tokinizer: &mut Tokinizer) -> bool {
         let ch = tokinizer.get_char();
-        return ch == ' ';
+        return KaramelWhitespaceType::from_char(ch).is_some();
     }
 
     fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType> {
@@ -14,13 +14,15 @@ impl TokenParser for WhitespaceParser {
         let mut ch                   = tokinizer.get_char();
         let start_column = tokinizer.column;
 
-        while !tokinizer.is_end() && ch == ' ' {
+        /* Spaces and tabs are different tokens */
+        let whitespace_type = KaramelWhitespaceType::from_char(ch).unwrap();
+        while !tokinizer.is_end() && KaramelWhitespaceType::from_char(ch) == Some(whitespace_type) {
             tokinizer.increase_index();
-            whitespace_count += 1;
+            whitespace_count = whitespace_count.saturating_add(1);
             ch = tokinizer.get_char();
         }
 
-        tokinizer.add_token(start_column, KaramelTokenType::WhiteSpace(whitespace_count));
+        tokinizer.add_token(start_column, KaramelTokenType::WhiteSpace(whitespace_count, whitespace_type));
         return Ok(());
     }
 }
\ No newline at end of file
@@ -14,6 +14,7 @@ impl SyntaxParserTrait for AssignmentParser {
         let index_backup = parser.get_index();
         parser.indentation_check()?;
 
+        let position = parser.current_position();
         let variable = ExpressionParser::parse(parser)?;
 
         match variable {
@@ -44,7 +45,8 @@ impl SyntaxParserTrait for AssignmentParser {
             let assignment_ast = KaramelAstType::Assignment {
                 variable: Rc::new(variable),
                 operator,
-                expression: Rc::new(expression.unwrap())
+                expression: Rc::new(expression.unwrap()),
+                position
             };
 
             return Ok(assignment_ast);
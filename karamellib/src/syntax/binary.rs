@@ -9,31 +9,105 @@ use crate::error::KaramelErrorType;
 
 use super::util::with_flag;
 
-pub struct ModuloParser;
-pub struct MultiplyDivideParser;
-pub struct AddSubtractParser;
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Associativity {
+    Left,
+    Right
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OperatorKind {
+    /* Generates KaramelAstType::Binary */
+    Binary,
+
+    /* Generates KaramelAstType::Control */
+    Control
+}
+
+#[derive(Debug)]
+pub struct OperatorPrecedence {
+    pub operator: KaramelOperatorType,
+    pub precedence: u8,
+    pub associativity: Associativity,
+    pub kind: OperatorKind
+}
+
+macro_rules! operator_precedence {
+    ($operator:ident, $precedence:expr, $associativity:ident, $kind:ident) => {
+        OperatorPrecedence {
+            operator: KaramelOperatorType::$operator,
+            precedence: $precedence,
+            associativity: Associativity::$associativity,
+            kind: OperatorKind::$kind
+        }
+    };
+}
+
+/* Higher precedence binds stronger, new infix operators only need a new line */
+pub static OPERATOR_PRECEDENCES: &[OperatorPrecedence] = &[
+    operator_precedence!(Or,               1, Left,  Control),
+    operator_precedence!(And,              2, Left,  Control),
+    operator_precedence!(Equal,            3, Left,  Control),
+    operator_precedence!(NotEqual,         3, Left,  Control),
+    operator_precedence!(GreaterEqualThan, 4, Left,  Control),
+    operator_precedence!(GreaterThan,      4, Left,  Control),
+    operator_precedence!(LessEqualThan,    4, Left,  Control),
+    operator_precedence!(LessThan,         4, Left,  Control),
+    operator_precedence!(Addition,         5, Left,  Binary),
+    operator_precedence!(Subtraction,      5, Left,  Binary),
+    operator_precedence!(Modulo,           6, Left,  Binary),
+    operator_precedence!(Multiplication,   7, Left,  Binary),
+    operator_precedence!(Division,         7, Left,  Binary),
+    operator_precedence!(Exponent,         8, Right, Binary)
+];
+
+pub fn get_operator_precedence(operator: KaramelOperatorType) -> Option<&'static OperatorPrecedence> {
+    OPERATOR_PRECEDENCES.iter().find(|item| item.operator == operator)
+}
+
+pub struct BinaryParser;
 
-impl SyntaxParserTrait for ModuloParser {
+impl SyntaxParserTrait for BinaryParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
-        return parse_binary::<MultiplyDivideParser>(parser, &[KaramelOperatorType::Modulo]);
+        parse_binary(parser, 0)
     }
 }
 
-impl SyntaxParserTrait for MultiplyDivideParser {
-    fn parse(parser: &SyntaxParser) -> AstResult {
-        return parse_binary::<UnaryParser>(parser, &[KaramelOperatorType::Multiplication, KaramelOperatorType::Division]);
+fn next_operator(parser: &SyntaxParser, min_precedence: u8) -> Option<&'static OperatorPrecedence> {
+    match parser.peek_token() {
+        Ok(Token { token_type: KaramelTokenType::Operator(operator), .. }) => get_operator_precedence(*operator).filter(|item| item.precedence >= min_precedence),
+        _ => None
     }
 }
 
-impl SyntaxParserTrait for AddSubtractParser {
-    fn parse(parser: &SyntaxParser) -> AstResult {
-        parse_binary::<ModuloParser>(parser, &[KaramelOperatorType::Addition, KaramelOperatorType::Subtraction])
+fn create_ast(operator: &OperatorPrecedence, left: KaramelAstType, right: KaramelAstType) -> KaramelAstType {
+    match (operator.kind, operator.operator) {
+        /* 'a <= b' is converted to 'b >= a' and 'a < b' is converted to 'b > a' */
+        (OperatorKind::Control, KaramelOperatorType::LessEqualThan) => KaramelAstType::Control {
+            left: Rc::new(right),
+            operator: KaramelOperatorType::GreaterEqualThan,
+            right: Rc::new(left)
+        },
+        (OperatorKind::Control, KaramelOperatorType::LessThan) => KaramelAstType::Control {
+            left: Rc::new(right),
+            operator: KaramelOperatorType::GreaterThan,
+            right: Rc::new(left)
+        },
+        (OperatorKind::Control, operator) => KaramelAstType::Control {
+            left: Rc::new(left),
+            operator,
+            right: Rc::new(right)
+        },
+        (OperatorKind::Binary, operator) => KaramelAstType::Binary {
+            left: Rc::new(left),
+            operator,
+            right: Rc::new(right)
+        }
     }
 }
 
-pub fn parse_binary<T: SyntaxParserTrait>(parser: &SyntaxParser, operators: &[KaramelOperatorType]) -> AstResult {
-    let mut functions_updated_for_temp = false;
-    let mut left_expr = T::parse(parser)?;
+pub fn parse_binary(parser: &SyntaxParser, min_precedence: u8) -> AstResult {
+    let mut left_expr = UnaryParser::parse(parser)?;
     match left_expr {
         KaramelAstType::None => return Ok(left_expr),
         _ => ()
@@ -42,32 +116,30 @@ pub fn parse_binary<T: SyntaxParserTrait>(parser: &SyntaxParser, operators: &[Ka
     loop {
         let index_backup = parser.get_index();
         parser.cleanup_whitespaces();
-        
-        if let Some(operator) = parser.match_operator(operators) {
-            if !functions_updated_for_temp {
-                update_functions_for_temp_return(&left_expr);
-                functions_updated_for_temp = true;
+
+        let operator = match next_operator(parser, min_precedence) {
+            Some(operator) => operator,
+            None => {
+                parser.set_index(index_backup);
+                break;
             }
-            
-            parser.cleanup_whitespaces();
-            
-            let right_expr = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || T::parse(parser));
-            match right_expr {
-                Ok(KaramelAstType::None) => return Err(KaramelErrorType::RightSideOfExpressionNotFound),
-                Ok(_) => (),
-                Err(_) => return right_expr
-            };
-
-            left_expr = KaramelAstType::Binary {
-                left: Rc::new(left_expr),
-                operator,
-                right: Rc::new(right_expr.unwrap())
-            };
-        }
-        else {
-            parser.set_index(index_backup);
-            break;
-        }
+        };
+
+        parser.consume_token();
+        update_functions_for_temp_return(&left_expr);
+        parser.cleanup_whitespaces();
+
+        /* Right associative operators accept the same precedence on the right side, 2 ** 3 ** 2 is 2 ** (3 ** 2) */
+        let right_precedence = match operator.associativity {
+            Associativity::Left => operator.precedence + 1,
+            Associativity::Right => operator.precedence
+        };
+
+        let right_expr = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || parse_binary(parser, right_precedence))?;
+        left_expr = match right_expr {
+            KaramelAstType::None => return Err(KaramelErrorType::RightSideOfExpressionNotFound),
+            _ => create_ast(operator, left_expr, right_expr)
+        };
     }
 
     Ok(left_expr)
@@ -34,6 +34,9 @@ impl BlockParser {
 
         loop {
             parser.indentation_check()?;
+
+            /* Leading ';' is an empty statement */
+            BlockParser::skip_semicolons(parser);
             let ast = map_parser(parser, &[FunctionDefinationParser::parse, StatementParser::parse, ExpressionParser::parse, NewlineParser::parse])?;
     
             match ast {
@@ -42,6 +45,11 @@ impl BlockParser {
                 _ => block_asts.push(Rc::new(ast))
             };
 
+            /* Statements on the same line can be separated with ';' */
+            if BlockParser::statement_separator(parser) {
+                continue;
+            }
+
             if !multiline { break; }
             
             parser.cleanup();
@@ -56,4 +64,40 @@ impl BlockParser {
             _ => Ok(KaramelAstType::Block(block_asts.to_vec()))
         }
     }
+
+    fn statement_separator(parser: &SyntaxParser) -> bool {
+        let index_backup = parser.get_index();
+
+        /* Repeated ';' are empty statements */
+        if BlockParser::skip_semicolons(parser) {
+
+            /* Trailing ';' does not continue the line */
+            if parser.peek_token().is_ok() && !parser.get_newline().0 {
+                return true;
+            }
+            return false;
+        }
+
+        parser.set_index(index_backup);
+        false
+    }
+
+    fn skip_semicolons(parser: &SyntaxParser) -> bool {
+        let index_backup = parser.get_index();
+        let mut found = false;
+
+        loop {
+            parser.cleanup_whitespaces();
+            if parser.match_operator(&[KaramelOperatorType::Semicolon]).is_none() {
+                break;
+            }
+            found = true;
+        }
+
+        match found {
+            true => parser.cleanup_whitespaces(),
+            false => parser.set_index(index_backup)
+        };
+        found
+    }
 }
@@ -4,7 +4,7 @@ use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag, ExtensionSyntaxParser};
 use crate::syntax::func_call::FuncCallParser;
 use crate::syntax::unary::UnaryParser;
-use crate::syntax::control::OrParser;
+use crate::syntax::binary::BinaryParser;
 use crate::syntax::util::update_functions_for_temp_return;
 use crate::compiler::ast::KaramelAstType;
 use crate::compiler::value::KaramelPrimative;
@@ -16,7 +16,7 @@ pub struct ExpressionParser;
 
 impl SyntaxParserTrait for ExpressionParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
-        let mut ast = OrParser::parse(parser)?;
+        let mut ast = BinaryParser::parse(parser)?;
     
         loop {
             let index_backup = parser.get_index();
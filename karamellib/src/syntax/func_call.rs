@@ -6,7 +6,7 @@ use crate::syntax::util::update_functions_for_temp_return;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag, ExtensionSyntaxParser};
 use crate::syntax::expression::ExpressionParser;
 use crate::syntax::primative::PrimativeParser;
-use crate::compiler::ast::KaramelAstType;
+use crate::compiler::ast::{KaramelAstPosition, KaramelAstType};
 use crate::syntax::util::map_parser;
 use crate::error::KaramelErrorType;
 
@@ -23,12 +23,13 @@ impl SyntaxParserTrait for FuncCallParser {
         let token = parser.peek_token();
 
         if token.is_ok() {
+            let position = parser.current_position();
             let mut function_name = map_parser(parser, &[PrimativeParser::parse_module_path, PrimativeParser::parse_symbol])?;
 
             match &function_name {
                 KaramelAstType::None => (),
                 _ => { 
-                    let parse_result = FuncCallParser::parse_suffix(&mut function_name, parser)?;
+                    let parse_result = FuncCallParser::parse_call(&mut function_name, parser, position)?;
                     match parse_result {
                         KaramelAstType::None => (),
                         _ => return Ok(parse_result)
@@ -52,6 +53,13 @@ impl ExtensionSyntaxParser for FuncCallParser {
     }
 
     fn parse_suffix(ast: &mut KaramelAstType, parser: &SyntaxParser) -> AstResult {
+        /* Start of the called expression is not known, last token of it is used */
+        FuncCallParser::parse_call(ast, parser, parser.previous_position())
+    }
+}
+
+impl FuncCallParser {
+    fn parse_call(ast: &mut KaramelAstType, parser: &SyntaxParser, position: KaramelAstPosition) -> AstResult {
 
         let index_backup = parser.get_index();
         let parser_flags  = parser.flags.get();
@@ -68,7 +76,13 @@ impl ExtensionSyntaxParser for FuncCallParser {
             while continue_to_parse {
                 parser.cleanup_whitespaces();
                 
-                let param_expression = ExpressionParser::parse(parser);
+                let param_expression = match parser.match_operator(&[KaramelOperatorType::Multiplication]) {
+                    Some(_) => match ExpressionParser::parse(parser)? {
+                        KaramelAstType::None => return Err(KaramelErrorType::SyntaxError),
+                        expression => Ok(KaramelAstType::Spread(Rc::new(expression)))
+                    },
+                    None => ExpressionParser::parse(parser)
+                };
                 match param_expression {
                     Err(_) => return param_expression,
                     _ => ()
@@ -103,7 +117,8 @@ impl ExtensionSyntaxParser for FuncCallParser {
                 assign_to_temp: Cell::new(parser.flags.get().contains(SyntaxFlag::IN_EXPRESSION)
                                 || parser.flags.get().contains(SyntaxFlag::IN_ASSIGNMENT)
                                 || parser.flags.get().contains(SyntaxFlag::IN_FUNCTION_ARG)
-                                || parser.flags.get().contains(SyntaxFlag::IN_RETURN))
+                                || parser.flags.get().contains(SyntaxFlag::IN_RETURN)),
+                position
             });
         }
         /* parse for 'object.method()' */
@@ -116,7 +131,8 @@ impl ExtensionSyntaxParser for FuncCallParser {
                 KaramelAstType::FuncCall {
                     func_name_expression,
                     arguments: _,
-                    assign_to_temp: _ 
+                    assign_to_temp: _,
+                    position: _
                 } => {
                     match &**func_name_expression {
                         KaramelAstType::Symbol(_) => {
@@ -8,6 +8,35 @@ use std::rc::Rc;
 
 pub struct FunctionDefinationParser;
 
+/* Only 'fonk' defines a function, these are reported with a clear error instead of a syntax error */
+const UNSUPPORTED_FUNCTION_KEYWORDS: [&str; 3] = ["fon", "fn", "fonksiyon"];
+
+impl FunctionDefinationParser {
+    fn check_unsupported_keyword(parser: &SyntaxParser) -> Result<(), KaramelErrorType> {
+        let keyword = match parser.peek_token() {
+            Ok(Token { token_type: KaramelTokenType::Symbol(keyword), .. }) if UNSUPPORTED_FUNCTION_KEYWORDS.contains(&keyword.as_str()) => keyword.to_string(),
+            _ => return Ok(())
+        };
+
+        /* Only definition like usages are reported, 'fn' is still a valid variable name */
+        let keyword_index = parser.get_index();
+        parser.consume_token();
+        let is_definition = match PrimativeParser::parse_symbol(parser)? {
+            KaramelAstType::Symbol(_) => {
+                parser.cleanup_whitespaces();
+                parser.check_operator(&KaramelOperatorType::LeftParentheses) || parser.check_operator(&KaramelOperatorType::ColonMark)
+            },
+            _ => false
+        };
+
+        parser.set_index(keyword_index);
+        match is_definition {
+            true => Err(KaramelErrorType::FunctionKeywordNotValid(keyword)),
+            false => Ok(())
+        }
+    }
+}
+
 impl SyntaxParserTrait for FunctionDefinationParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
         let index_backup = parser.get_index();
@@ -19,6 +48,8 @@ impl SyntaxParserTrait for FunctionDefinationParser {
             parser.cleanup_whitespaces();
 
             let mut arguments = Vec::new();
+            let mut argument_positions = Vec::new();
+            let position = parser.current_position();
             let name_expression = PrimativeParser::parse_symbol(parser)?;
             let function_name = match name_expression {
                 KaramelAstType::Symbol(text) => text,
@@ -38,9 +69,13 @@ impl SyntaxParserTrait for FunctionDefinationParser {
                         break;
                     }
 
+                    let argument_position = parser.current_position();
                     let argument = PrimativeParser::parse_symbol(parser)?;
                     match argument {
-                        KaramelAstType::Symbol(text) => arguments.push(text),
+                        KaramelAstType::Symbol(text) => {
+                            arguments.push(text);
+                            argument_positions.push(argument_position);
+                        },
                         _ => return Err(KaramelErrorType::ArgumentMustBeText)
                     };
 
@@ -101,12 +136,16 @@ impl SyntaxParserTrait for FunctionDefinationParser {
             let function_defination_ast = KaramelAstType::FunctionDefination {
                 name: function_name,
                 body: Rc::new(body),
-                arguments: arguments
+                arguments: arguments,
+                argument_positions,
+                position
             };
 
             parser.set_indentation(indentation);
             return Ok(function_defination_ast);
         }
+
+        FunctionDefinationParser::check_unsupported_keyword(parser)?;
         
         parser.set_index(index_backup);
         return Ok(KaramelAstType::None);
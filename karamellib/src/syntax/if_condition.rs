@@ -6,7 +6,7 @@ use crate::syntax::expression::ExpressionParser;
 use crate::compiler::ast::{KaramelAstType, KaramelIfStatementElseItem};
 use crate::syntax::block::{SingleLineBlockParser, MultiLineBlockParser};
 use crate::error::KaramelErrorType;
-use crate::syntax::control::OrParser;
+use crate::syntax::binary::BinaryParser;
 
 pub struct IfConditiontParser;
 
@@ -16,7 +16,7 @@ impl SyntaxParserTrait for IfConditiontParser {
         parser.indentation_check()?;
 
         let indentation = parser.get_indentation();
-        let expression = OrParser::parse(parser)?;
+        let expression = BinaryParser::parse(parser)?;
         parser.cleanup_whitespaces();
 
         if parser.match_keyword(KaramelKeywordType::If) {
@@ -1,8 +1,10 @@
+use std::cell::Cell;
 use std::rc::Rc;
 
 use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag};
-use crate::compiler::ast::{KaramelAstType};
+use crate::compiler::ast::{KaramelAstPosition, KaramelAstType};
+use crate::compiler::value::KaramelPrimative;
 use crate::syntax::block::{SingleLineBlockParser, MultiLineBlockParser};
 use crate::syntax::expression::ExpressionParser;
 use crate::error::KaramelErrorType;
@@ -22,17 +24,168 @@ pub enum LoopType {
     Endless
 }
 
+/// Variable of `her` loop and the position it is written at.
+struct ForEachVariable {
+    name: String,
+    position: KaramelAstPosition
+}
+
+/// Variables and source of `her` loop. 
+struct ForEachInfo {
+    key: ForEachVariable,
+    value: Option<ForEachVariable>,
+    source: KaramelAstType
+}
+
 pub struct WhileLoopParser;
 
+impl WhileLoopParser {
+    fn parse_loop_variable(parser: &SyntaxParser) -> Result<ForEachVariable, KaramelErrorType> {
+        parser.cleanup_whitespaces();
+        let position = parser.current_position();
+        let name = match parser.peek_token() {
+            Ok(Token { token_type: KaramelTokenType::Symbol(name), .. }) => name.to_string(),
+            _ => return Err(KaramelErrorType::MissingLoopVariable)
+        };
+        parser.consume_token();
+        parser.cleanup_whitespaces();
+        Ok(ForEachVariable { name, position })
+    }
+
+    fn parse_for_each(parser: &SyntaxParser) -> Result<ForEachInfo, KaramelErrorType> {
+        let key = Self::parse_loop_variable(parser)?;
+        let value = match parser.match_operator(&[KaramelOperatorType::Comma]) {
+            Some(_) => Some(Self::parse_loop_variable(parser)?),
+            None => None
+        };
+
+        let source = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
+        if let KaramelAstType::None = source {
+            return Err(KaramelErrorType::InvalidExpression);
+        }
+
+        parser.cleanup_whitespaces();
+        if !parser.match_keyword(KaramelKeywordType::In) {
+            return Err(KaramelErrorType::MissingIn);
+        }
+
+        Ok(ForEachInfo { key, value, source })
+    }
+
+    /*
+    'her' loop converted to scalar loop over a hidden list created by base functions.
+
+    Single variable form uses 'baz::elemanlar' and two variables form uses 'baz::çiftler'.
+    Example:
+        her anahtar, değer kayıtlar içinde:
+            toplam += değer
+
+    Converted:
+        !her_N = baz::çiftler(kayıtlar)
+        döngü !her_N_sıra = 0, !her_N_sıra < !her_N.uzunluk(), ++!her_N_sıra:
+            anahtar = !her_N[!her_N_sıra][0]
+            değer = !her_N[!her_N_sıra][1]
+            toplam += değer
+    */
+    fn build_for_each(id: usize, position: KaramelAstPosition, info: ForEachInfo, body: KaramelAstType) -> KaramelAstType {
+        let items = Rc::new(KaramelAstType::Symbol(format!("!her_{}", id)));
+        let index = Rc::new(KaramelAstType::Symbol(format!("!her_{}_sıra", id)));
+        let function_name = match info.value {
+            Some(_) => "çiftler",
+            None => "elemanlar"
+        };
+
+        let assign = |variable: &ForEachVariable, expression: KaramelAstType| Rc::new(KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::Symbol(variable.name.to_string())),
+            operator: KaramelOperatorType::Assign,
+            expression: Rc::new(expression),
+            position: variable.position
+        });
+
+        let current_item = KaramelAstType::Indexer { body: items.clone(), indexer: index.clone() };
+        let pair_item = |position: f64| KaramelAstType::Indexer { 
+            body: Rc::new(current_item.clone()), 
+            indexer: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(position))))
+        };
+
+        let mut loop_body = Vec::new();
+        match &info.value {
+            Some(value) => {
+                loop_body.push(assign(&info.key, pair_item(0.0)));
+                loop_body.push(assign(value, pair_item(1.0)));
+            },
+            None => loop_body.push(assign(&info.key, current_item.clone()))
+        };
+        loop_body.push(Rc::new(body));
+
+        let source = KaramelAstType::FuncCall {
+            func_name_expression: Rc::new(KaramelAstType::ModulePath(vec!["baz".to_string(), function_name.to_string()])),
+            arguments: vec![Rc::new(info.source)],
+            assign_to_temp: Cell::new(true),
+            position
+        };
+
+        let length = KaramelAstType::AccessorFuncCall {
+            source: items.clone(),
+            indexer: Rc::new(KaramelAstType::FuncCall {
+                func_name_expression: Rc::new(KaramelAstType::Symbol("uzunluk".to_string())),
+                arguments: Vec::new(),
+                assign_to_temp: Cell::new(true),
+                position
+            }),
+            assign_to_temp: Cell::new(true)
+        };
+
+        KaramelAstType::Block(vec![
+            Rc::new(KaramelAstType::Assignment {
+                variable: items.clone(),
+                operator: KaramelOperatorType::Assign,
+                expression: Rc::new(source),
+                position
+            }),
+            Rc::new(KaramelAstType::Loop {
+                loop_type: LoopType::Scalar {
+                    variable: Rc::new(KaramelAstType::Assignment {
+                        variable: index.clone(),
+                        operator: KaramelOperatorType::Assign,
+                        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(0.0)))),
+                        position
+                    }),
+                    control: Rc::new(KaramelAstType::Control {
+                        left: Rc::new(length),
+                        operator: KaramelOperatorType::GreaterThan,
+                        right: index.clone()
+                    }),
+                    increment: Rc::new(KaramelAstType::PrefixUnary {
+                        operator: KaramelOperatorType::Increment,
+                        expression: index.clone(),
+                        assign_to_temp: Cell::new(false)
+                    })
+                },
+                body: Rc::new(KaramelAstType::Block(loop_body))
+            })
+        ])
+    }
+}
+
 impl SyntaxParserTrait for WhileLoopParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
         parser.indentation_check()?;
 
         let indentation = parser.get_indentation();
-        let loop_type = match parser.match_keywords(&[KaramelKeywordType::Endless, KaramelKeywordType::While]) {
+        let loop_id = parser.get_index();
+        let position = parser.current_position();
+        let mut for_each = None;
+        let loop_type = match parser.match_keywords(&[KaramelKeywordType::Endless, KaramelKeywordType::While, KaramelKeywordType::ForEach]) {
             // Endless loop
             Some(KaramelKeywordType::Endless) => LoopType::Endless,
 
+            // Iterate over list or dictionary
+            Some(KaramelKeywordType::ForEach) => {
+                for_each = Some(Self::parse_for_each(parser)?);
+                LoopType::Endless
+            },
+
             // While loop
             Some(KaramelKeywordType::While) => {
 
@@ -60,7 +213,7 @@ impl SyntaxParserTrait for WhileLoopParser {
                     },
 
                     // It is scalar loop
-                    KaramelAstType::Assignment { variable: _, operator, expression: _ } => {
+                    KaramelAstType::Assignment { variable: _, operator, expression: _, position: _ } => {
                         /* Loop just accept assignation operator, other operators are not valid */
                         if !operator.is_same(KaramelOperatorType::Assign) {
                             return Err(KaramelErrorType::AssignOperatorRequiredForLoop);
@@ -127,6 +280,10 @@ impl SyntaxParserTrait for WhileLoopParser {
         parser.set_indentation(indentation);
         parser.flags.set(parser_flags);
 
+        if let Some(info) = for_each {
+            return Ok(Self::build_for_each(loop_id, position, info, body));
+        }
+
         return Ok(KaramelAstType::Loop {
             loop_type: loop_type,
             body: Rc::new(body)
@@ -157,7 +314,7 @@ mod tests {
                     _ => ()
                 };
 
-                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                let syntax = SyntaxParser::new(parser.tokens());
                 assert_eq!(syntax.parse(), $result);
             }
         };
@@ -170,7 +327,8 @@ mod tests {
         body: Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
             operator: KaramelOperatorType::Assign,
-            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+            position: KaramelAstPosition::default()
         })
     } )));
 
@@ -184,7 +342,8 @@ mod tests {
                 body: Rc::new(KaramelAstType::Assignment {
                     variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
                     operator: KaramelOperatorType::Assign,
-                    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+                    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+                    position: KaramelAstPosition::default()
                 })
             } )
     } )));
@@ -200,7 +359,8 @@ mod tests {
         body: Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
             operator: KaramelOperatorType::Assign,
-            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+            position: KaramelAstPosition::default()
         })
     } )));
 
@@ -210,12 +370,14 @@ mod tests {
     loop_type: LoopType::Simple(Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("test".to_string())),
         arguments: Vec::new(),
-        assign_to_temp: Cell::new(true)
+        assign_to_temp: Cell::new(true),
+        position: KaramelAstPosition::default()
     })),
         body: Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
             operator: KaramelOperatorType::Assign,
-            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+            position: KaramelAstPosition::default()
         })
     } )));
 
@@ -226,7 +388,8 @@ mod tests {
         body: Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
             operator: KaramelOperatorType::Assign,
-            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+            position: KaramelAstPosition::default()
         })
     } )));
 
@@ -237,7 +400,8 @@ mod tests {
             variable: Rc::new(KaramelAstType::Assignment {
                 variable: Rc::new(KaramelAstType::Symbol("i".to_string())),
                 operator: KaramelOperatorType::Assign,
-                expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+                expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+                position: KaramelAstPosition::default()
             }), 
             control: Rc::new(KaramelAstType::Control {
                 left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))), 
@@ -265,4 +429,13 @@ doğru
 test_compare!(scalar_5, r#"döngü i = 1, i < 1,
 doğru
 "#, Err(KaramelError::new(0, 19, KaramelErrorType::ColonMarkMissing)));
+test_compare!(for_each_1, r#"her i [1, 2] 
+    doğru
+"#, Err(KaramelError::new(0, 12, KaramelErrorType::MissingIn)));
+test_compare!(for_each_2, r#"her [1, 2] içinde:
+    doğru
+"#, Err(KaramelError::new(0, 5, KaramelErrorType::MissingLoopVariable)));
+test_compare!(for_each_3, r#"her i, [1, 2] içinde:
+    doğru
+"#, Err(KaramelError::new(0, 8, KaramelErrorType::MissingLoopVariable)));
 }
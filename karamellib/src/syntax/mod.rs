@@ -2,7 +2,6 @@ pub mod primative;
 pub mod unary;
 pub mod util;
 pub mod binary;
-pub mod control;
 pub mod block;
 pub mod assignment;
 pub mod func_call;
@@ -16,22 +15,22 @@ pub mod loop_item;
 pub mod expression;
 pub mod load_module;
 
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::rc::Rc;
-use std::vec::Vec;
 use std::cell::Cell;
 
 use crate::types::*;
 use self::block::MultiLineBlockParser;
-use crate::compiler::ast::KaramelAstType;
+use crate::compiler::ast::{KaramelAstPosition, KaramelAstType};
 use crate::error::*;
 
 use bitflags::bitflags;
 
 pub type ParseType = fn(parser: &SyntaxParser) -> AstResult;
 
-pub struct SyntaxParser {
-    pub tokens: Vec<Token>,
+pub struct SyntaxParser<'a> {
+    /// Tokens are borrowed from the `Parser` when it is possible, owned tokens are kept as is.
+    pub tokens: Cow<'a, [Token]>,
     pub index: Cell<usize>,
     pub indentation: Cell<usize>,
     pub flags: Cell<SyntaxFlag>
@@ -59,10 +58,10 @@ pub trait ExtensionSyntaxParser: Sized {
     fn parse_suffix(ast: &mut KaramelAstType, parser: &SyntaxParser) -> AstResult;
 }
 
-impl SyntaxParser {
-    pub fn new(tokens: Vec<Token>) -> SyntaxParser {
+impl<'a> SyntaxParser<'a> {
+    pub fn new<T: Into<Cow<'a, [Token]>>>(tokens: T) -> SyntaxParser<'a> {
         SyntaxParser {
-            tokens,
+            tokens: tokens.into(),
             index: Cell::new(0),
             indentation: Cell::new(0),
             flags: Cell::new(SyntaxFlag::NONE)
@@ -76,29 +75,23 @@ impl SyntaxParser {
                 
                 if let Ok(token) = self.peek_token() {
                     log::debug!("We forget this : {:?}", token);
-                    return Err(KaramelError {
-                        error_type: KaramelErrorType::SyntaxError,
-                        line: token.line,
-                        column: token.start
-                    });
+
+                    /* Commas are only valid between arguments and items */
+                    let error = match self.check_operator(&KaramelOperatorType::Comma) {
+                        true => KaramelErrorType::UnexpectedComma,
+                        false => KaramelErrorType::SyntaxError
+                    };
+                    return Err(KaramelError::with_span(token.line, token.start, token.span.clone(), error));
                 }
                 Ok(Rc::new(ast))
             },
             Err(error) => {
                 if let Ok(token) = self.valid_token() {
                     log::debug!("Syntax parse failed : {:?}", token);
-                    return Err(KaramelError {
-                        error_type: error,
-                        line: token.line,
-                        column: token.end
-                    });
+                    return Err(KaramelError::with_span(token.line, token.end, token.span.clone(), error));
                 }
 
-                return Err(KaramelError {
-                    error_type: error,
-                    line: 0,
-                    column: 0
-                });
+                return Err(KaramelError::new(0, 0, error));
             }
         };
     }
@@ -143,6 +136,23 @@ impl SyntaxParser {
         }
     }
 
+    /// Position of the current token, empty at the end of the tokens.
+    pub fn current_position(&self) -> KaramelAstPosition {
+        match self.peek_token() {
+            Ok(token) => KaramelAstPosition::new(token.line, token.start),
+            Err(_) => KaramelAstPosition::default()
+        }
+    }
+
+    /// Position of the last token before the current one, whitespaces are skipped.
+    pub fn previous_position(&self) -> KaramelAstPosition {
+        self.tokens[..self.index.get()].iter()
+            .rev()
+            .find(|token| !matches!(token.token_type, KaramelTokenType::NewLine(..) | KaramelTokenType::WhiteSpace(..)))
+            .map(|token| KaramelAstPosition::new(token.line, token.start))
+            .unwrap_or_default()
+    }
+
     pub fn valid_token(&self) -> Result<&Token, ()> {
         let mut index = self.index.get() + 1;
         
@@ -150,8 +160,8 @@ impl SyntaxParser {
             match index.checked_sub(1) {
                 Some(index) => match self.tokens.get(index) {
                     Some(token) => match token.token_type {
-                        KaramelTokenType::NewLine(_) => (),
-                        KaramelTokenType::WhiteSpace(_) => (),
+                        KaramelTokenType::NewLine(..) => (),
+                        KaramelTokenType::WhiteSpace(..) => (),
                         _ => return Ok(token)
                     },
                     None => ()
@@ -202,7 +212,7 @@ impl SyntaxParser {
         let token = self.peek_token();
         if token.is_err() { return (false, 0); }
         return match token.unwrap().token_type {
-            KaramelTokenType::NewLine(size) => (true, size as usize),
+            KaramelTokenType::NewLine(size, _) => (true, size as usize),
             _ => (false, 0)
         }
     }
@@ -242,7 +252,7 @@ impl SyntaxParser {
         loop {
             if let Ok(current_token) = self.peek_token() {
                 let done = match current_token.token_type {
-                    KaramelTokenType::WhiteSpace(_) => false,
+                    KaramelTokenType::WhiteSpace(..) => false,
                     _ => true
                 };
 
@@ -266,8 +276,8 @@ impl SyntaxParser {
         loop {
             if let Ok(current_token) = self.peek_token() {                
                 match current_token.token_type {
-                    KaramelTokenType::NewLine(_) =>  true,
-                    KaramelTokenType::WhiteSpace(_) => true,
+                    KaramelTokenType::NewLine(..) =>  true,
+                    KaramelTokenType::WhiteSpace(..) => true,
                     _ => break
                 };
 
@@ -279,6 +289,10 @@ impl SyntaxParser {
         }
     }
 
+    fn is_whitespace_left(&self) -> bool {
+        self.tokens[self.index.get()..].iter().all(|token| matches!(token.token_type, KaramelTokenType::WhiteSpace(..) | KaramelTokenType::NewLine(..)))
+    }
+
     fn indentation_check(&self) -> AstResult {
         if self.next_token().is_err() {
             return Ok(KaramelAstType::None);
@@ -286,10 +300,10 @@ impl SyntaxParser {
 
         while let Ok(current_token) = self.peek_token() {                
             let success = match current_token.token_type {
-                KaramelTokenType::NewLine(size) => {
-                    let token_type = &self.next_token().unwrap().token_type;
-                    if let KaramelTokenType::NewLine(_) = token_type {
-                        /* If next token is newline, no need to check */
+                KaramelTokenType::NewLine(size, _) => {
+                    let next_token = self.next_token();
+                    if let Ok(Token { token_type: KaramelTokenType::NewLine(..), .. }) | Err(_) = next_token {
+                        /* If next token is newline or the end of the file, no need to check */
                         true
                     }
                     else {
@@ -298,8 +312,9 @@ impl SyntaxParser {
                     }
                 },
                 
-                KaramelTokenType::WhiteSpace(size) => {
-                    size == self.indentation.get() as u8 
+                KaramelTokenType::WhiteSpace(size, _) => {
+                    /* Whitespace only sources are accepted */
+                    size == self.indentation.get() as u8 || self.is_whitespace_left()
                 },
                 _ => break
             };
@@ -321,10 +336,10 @@ impl SyntaxParser {
 
         while let Ok(current_token) = self.peek_token() {               
             let success = match current_token.token_type {
-                KaramelTokenType::NewLine(size) => {
-                    let token_type = &self.next_token().unwrap().token_type;
-                    if let KaramelTokenType::NewLine(_) = token_type {
-                        /* If next token is newline, no need to check */
+                KaramelTokenType::NewLine(size, _) => {
+                    let next_token = self.next_token();
+                    if let Ok(Token { token_type: KaramelTokenType::NewLine(..), .. }) | Err(_) = next_token {
+                        /* If next token is newline or the end of the file, no need to check */
                         true
                     }
                     else {
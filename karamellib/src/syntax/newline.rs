@@ -10,13 +10,13 @@ impl SyntaxParserTrait for NewlineParser {
         loop {
             if let Ok(token) = parser.peek_token() {
                 match token.token_type {
-                    KaramelTokenType::NewLine(_) => {
+                    KaramelTokenType::NewLine(..) => {
                         parser.indentation_check()?;
                         result = KaramelAstType::NewLine;
                         parser.consume_token();
                         continue;
                     },
-                    KaramelTokenType::WhiteSpace(_) => {
+                    KaramelTokenType::WhiteSpace(..) => {
                         result = KaramelAstType::NewLine;
                         parser.consume_token();
                         continue;
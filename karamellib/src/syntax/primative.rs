@@ -21,7 +21,7 @@ impl PrimativeParser {
         }
 
         let result = match &token.unwrap().token_type {
-            KaramelTokenType::Integer(int)      => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(*int as f64)))),
+            KaramelTokenType::Integer(int, _)   => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(*int as f64)))),
             KaramelTokenType::Double(double)    => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(*double)))),
             KaramelTokenType::Text(text)        => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::clone(text))))),
             KaramelTokenType::Keyword(keyword)  => {
@@ -55,12 +55,13 @@ impl PrimativeParser {
             parser.cleanup_whitespaces();
 
             loop {
+                parser.cleanup_whitespaces();
+
+                /* Empty list or trailing comma */
                 if parser.check_operator(&KaramelOperatorType::SquareBracketEnd) {
                     break;
                 }
 
-                parser.cleanup_whitespaces();
-
                 let ast = ExpressionParser::parse(parser);
                 if is_ast_empty(&ast) {
                     return err_or_message(ast, KaramelErrorType::InvalidListItem);
@@ -92,29 +93,17 @@ impl PrimativeParser {
             parser.cleanup();
 
             loop {
+                parser.cleanup();
+
+                /* Empty dictionary or trailing comma */
                 if parser.check_operator(&KaramelOperatorType::CurveBracketEnd) {
                     break;
                 }
 
-                parser.cleanup();
-
-                let key_ast = Self::parse_basic_primatives(parser);
-                if is_ast_empty(&key_ast) {
-                    return err_or_message(key_ast, KaramelErrorType::DictionaryKeyNotValid);
+                let key = Self::parse_dict_key(parser);
+                if is_ast_empty(&key) {
+                    return err_or_message(key, KaramelErrorType::DictionaryKeyNotValid);
                 }
-                
-                /* Check dictionary key */
-                let key = match key_ast {
-                    Ok(KaramelAstType::Primative(primative)) => {
-                        match &*primative {
-                            KaramelPrimative::Text(_) => primative.clone(),
-                            _ =>  {
-                                return Err(KaramelErrorType::DictionaryKeyNotValid);
-                            }
-                        }
-                    },
-                    _ => return Err(KaramelErrorType::DictionaryKeyNotValid)
-                };
 
                 parser.cleanup();
 
@@ -129,7 +118,7 @@ impl PrimativeParser {
                 }
   
                 dict_items.push(Rc::new(KaramelDictItem {
-                    key,
+                    key: Rc::new(key.unwrap()),
                     value: Rc::new(value.unwrap())
                 }));
 
@@ -150,6 +139,26 @@ impl PrimativeParser {
         return Ok(KaramelAstType::None);
     }
 
+    /* A single symbol before the colon is used as a text key, other keys are computed expressions */
+    fn parse_dict_key(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        if let Ok(KaramelAstType::Symbol(symbol)) = Self::parse_symbol(parser) {
+            parser.cleanup_whitespaces();
+            let colon_index = parser.get_index();
+            if parser.match_operator(&[KaramelOperatorType::ColonMark]).is_some() {
+                let is_module_path = parser.check_operator(&KaramelOperatorType::ColonMark);
+                parser.set_index(colon_index);
+
+                if !is_module_path {
+                    return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new(symbol)))));
+                }
+            }
+        }
+
+        parser.set_index(index_backup);
+        ExpressionParser::parse(parser)
+    }
+
     pub fn parse_symbol(parser: &SyntaxParser) -> AstResult {
         let index_backup = parser.get_index();
         parser.cleanup_whitespaces();
@@ -218,6 +227,10 @@ impl PrimativeParser {
                 return err_or_message(ast, KaramelErrorType::InvalidExpression);
             }
 
+            if parser.check_operator(&KaramelOperatorType::Comma) {
+                return Err(KaramelErrorType::UnexpectedComma);
+            }
+
             if parser.match_operator(&[KaramelOperatorType::RightParentheses]).is_none() {
                 return Err(KaramelErrorType::ParenthesesNotClosed);
             }
@@ -102,7 +102,7 @@ impl UnaryParser {
 
                     parser.consume_token();
                     match token.token_type {
-                        KaramelTokenType::Integer(integer) => return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(integer as f64 * opt)))),
+                        KaramelTokenType::Integer(integer, _) => return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(integer as f64 * opt)))),
                         KaramelTokenType::Double(double) => return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(double * opt)))),
                         _ => {
                             parser.set_index(index_backup);
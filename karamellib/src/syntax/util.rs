@@ -49,7 +49,7 @@ pub fn err_or_message(ast: AstResult, none_error: KaramelErrorType) -> AstResult
 
 pub fn update_functions_for_temp_return(ast: &KaramelAstType) {
     match ast {
-        KaramelAstType::FuncCall { func_name_expression: _, arguments: _, assign_to_temp } => {
+        KaramelAstType::FuncCall { func_name_expression: _, arguments: _, assign_to_temp, position: _ } => {
             assign_to_temp.set(true);
         },
         KaramelAstType::AccessorFuncCall {
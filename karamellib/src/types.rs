@@ -1,4 +1,5 @@
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::mem::{self, discriminant};
 use std::vec::Vec;
 use std::str::Chars;
@@ -6,8 +7,10 @@ use std::iter::Peekable;
 use std::result::Result;
 use std::hash::Hash;
 use std::rc::Rc;
+use std::ops::Range;
 use crate::{compiler::ast::KaramelAstType, error::KaramelError};
 use crate::error::KaramelErrorType;
+use lazy_static::*;
 
 pub type ParseResult        = Result<(), KaramelError>;
 pub type AstResult          = Result<KaramelAstType, KaramelErrorType>;
@@ -57,21 +60,24 @@ pub enum KaramelKeywordType {
     Break,
     Continue,
     While,
-    Load
+    Load,
+    ForEach,
+    In
 }
 
 impl KaramelKeywordType {
-    pub fn to_operator(&self) -> KaramelOperatorType {
+    /// Operator of the keywords like 've' and 'mod', other keywords are not operators.
+    pub fn to_operator(&self) -> Option<KaramelOperatorType> {
         match &self {
-            KaramelKeywordType::And              => KaramelOperatorType::And,
-            KaramelKeywordType::Or               => KaramelOperatorType::Or,
-            KaramelKeywordType::Modulo           => KaramelOperatorType::Modulo,
-            KaramelKeywordType::Not              => KaramelOperatorType::Not,
-            KaramelKeywordType::Equal            => KaramelOperatorType::Equal,
-            KaramelKeywordType::NotEqual         => KaramelOperatorType::NotEqual,
-            KaramelKeywordType::GreaterThan      => KaramelOperatorType::GreaterThan,
-            KaramelKeywordType::GreaterEqualThan => KaramelOperatorType::GreaterEqualThan,
-            _                                  => KaramelOperatorType::None
+            KaramelKeywordType::And              => Some(KaramelOperatorType::And),
+            KaramelKeywordType::Or               => Some(KaramelOperatorType::Or),
+            KaramelKeywordType::Modulo           => Some(KaramelOperatorType::Modulo),
+            KaramelKeywordType::Not              => Some(KaramelOperatorType::Not),
+            KaramelKeywordType::Equal            => Some(KaramelOperatorType::Equal),
+            KaramelKeywordType::NotEqual         => Some(KaramelOperatorType::NotEqual),
+            KaramelKeywordType::GreaterThan      => Some(KaramelOperatorType::GreaterThan),
+            KaramelKeywordType::GreaterEqualThan => Some(KaramelOperatorType::GreaterEqualThan),
+            _                                  => None
         }
     }
 }
@@ -89,6 +95,8 @@ pub static KEYWORDS: &[(&str, KaramelKeywordType)] = &[
     ("ve",     KaramelKeywordType::And),
     ("veya",   KaramelKeywordType::Or),
     ("boş",    KaramelKeywordType::Empty),
+    ("bos",    KaramelKeywordType::Empty),
+    ("yok",    KaramelKeywordType::Empty),
     ("mod",    KaramelKeywordType::Modulo),
     ("değil",         KaramelKeywordType::Not),
     ("degil",         KaramelKeywordType::Not),
@@ -101,9 +109,17 @@ pub static KEYWORDS: &[(&str, KaramelKeywordType)] = &[
     ("döngü",         KaramelKeywordType::While),
     ("dongu",         KaramelKeywordType::While),
     ("yükle",          KaramelKeywordType::Load),
-    ("yukle",          KaramelKeywordType::Load)
+    ("yukle",          KaramelKeywordType::Load),
+    ("her",           KaramelKeywordType::ForEach),
+    ("içinde",        KaramelKeywordType::In),
+    ("icinde",        KaramelKeywordType::In)
 ];
 
+lazy_static! {
+    /* Every symbol is looked up while tokenizing, map is created once from KEYWORDS */
+    pub static ref KEYWORD_MAP: HashMap<&'static str, KaramelKeywordType> = KEYWORDS.iter().cloned().collect();
+}
+
 #[derive(Clone, Copy)]
 #[derive(Debug)]
 #[derive(PartialEq)]
@@ -114,6 +130,7 @@ pub enum KaramelOperatorType {
     Multiplication,
     Division,
     Modulo,
+    Exponent,
     Increment,
     Deccrement,
     Assign,
@@ -157,18 +174,41 @@ pub enum KaramelOperatorType {
 #[derive(Debug)]
 #[derive(PartialEq)]
 pub enum KaramelTokenType {
-    Integer(i64),
+    /* Number system is kept for the diagnostics, value is already converted */
+    Integer(i64, KaramelNumberSystem),
     Double(f64),
     Symbol(Rc<String>),
     Operator(KaramelOperatorType),
     Text(Rc<String>),
     Keyword(KaramelKeywordType),
-    WhiteSpace(u8),
-    NewLine(u8)
+    /* Count of the consecutive spaces or tabs, it is limited with u8::MAX */
+    WhiteSpace(u8, KaramelWhitespaceType),
+
+    /* Every new line is a different token, count is the indentation of the next line */
+    NewLine(u8, KaramelWhitespaceType)
 }
 
 #[repr(C)]
-#[derive(Clone)]
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum KaramelWhitespaceType {
+    Space,
+    Tab
+}
+
+impl KaramelWhitespaceType {
+    pub fn from_char(ch: char) -> Option<KaramelWhitespaceType> {
+        match ch {
+            '\t' => Some(KaramelWhitespaceType::Tab),
+            _ if CharTraits::is_whitespace(&ch) => Some(KaramelWhitespaceType::Space),
+            _    => None
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
 #[derive(Debug)]
 #[derive(PartialEq)]
 pub enum KaramelNumberSystem {
@@ -183,6 +223,9 @@ pub struct Token {
     pub line      : u32,
     pub start    : u32,
     pub end    : u32,
+
+    /* Byte offsets of the token in the source */
+    pub span: Range<usize>,
     pub token_type: KaramelTokenType
 }
 
@@ -194,7 +237,16 @@ pub struct Tokinizer<'a> {
     pub iter_second: Peekable<Chars<'a>>,
     pub iter_third: Peekable<Chars<'a>>,
     pub data: String,
-    pub index: u32
+    pub index: u32,
+
+    /* Byte index where the next token starts */
+    pub token_index: u32,
+
+    /* Open '(', '[' and '{' count, new lines are not significant inside them */
+    pub bracket_depth: u32,
+
+    /* Character of the first indented line, other lines should use the same one */
+    pub indentation_type: Option<KaramelWhitespaceType>
 }
 
 impl Tokinizer<'_> {
@@ -224,9 +276,11 @@ impl Tokinizer<'_> {
             line: self.line,
             start,
             end: self.column,
+            span: self.token_index as usize..self.index as usize,
             token_type
         };
         self.tokens.push(token);
+        self.token_index = self.index;
     }
 
     pub fn increase_index(&mut self) {
@@ -245,8 +299,21 @@ impl Tokinizer<'_> {
     pub fn reset_column(& mut self) {
         self.column = 0;
     }
+
+    /// Byte range of the token currently being parsed. Covers at least the
+    /// current character so that an error always has something to underline.
+    pub fn current_span(&mut self) -> Range<usize> {
+        let start = self.token_index as usize;
+        let end = match self.index as usize {
+            end if end > start => end,
+            _ => start + self.get_char().len_utf8()
+        };
+        start..end
+    }
 }
 
+/// Every parser reads its characters from the tokinizer and adds its own token with `Tokinizer::add_token`,
+/// so parsers like comment parser could consume characters without adding any token.
 pub trait TokenParser {
     fn check(&self, tokinizer: &mut Tokinizer) -> bool;
     fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType>;
@@ -260,15 +327,14 @@ pub trait CharTraits {
 }
 
 impl CharTraits for char {
+    /* '\r' is a line break too, '\r\n' is handled as a single one by the tokinizer */
     fn is_new_line(&self) -> bool {
-        *self == '\n'
+        *self == '\n' || *self == '\r'
     }
 
+    /* Unicode spaces like U+00A0 are accepted, new line is not a whitespace */
     fn is_whitespace(&self) -> bool {
-        match *self {
-            ' ' | '\r' | '\t' => true,
-            _ => false
-        }
+        !self.is_new_line() && char::is_whitespace(*self)
     }
 
     fn is_symbol(&self) -> bool {
@@ -1,17 +1,18 @@
 use std::borrow::Borrow;
 use std::cell::RefCell;
 
-use crate::compiler::context::{ExecutionPathInfo, KaramelCompilerContext};
+use crate::compiler::context::{ContextConfig, ExecutionPathInfo, KaramelCompilerContext};
 use crate::file::read_module_or_script;
 use crate::{types::Token, vm::interpreter::run_vm};
 use crate::parser::*;
 use crate::compiler::*;
 use crate::syntax::SyntaxParser;
 use crate::logger::{CONSOLE_LOGGER, write_stderr};
-use crate::error::generate_error_message;
+use crate::error::{generate_backtrace, generate_error_message, KaramelDiagnostic, KaramelError};
 
 use log;
 use crate::types::VmObject;
+use crate::compiler::value::EMPTY_OBJECT;
 
 
 pub enum ExecutionSource {
@@ -36,7 +37,20 @@ pub struct ExecutionStatus {
     pub stderr: Option<RefCell<String>>,
     pub opcodes: Option<Vec<Token>>,
     pub memory_dump: Option<String>,
-    pub opcode_dump: Option<String>
+    pub opcode_dump: Option<String>,
+    pub diagnostics: Vec<KaramelDiagnostic>,
+
+    /// Error that stopped the program, runtime errors have the call stack at the time of the error.
+    pub error: Option<KaramelError>
+}
+
+/// Outputs of `execute`, stdout and stderr are captured instead of written to the console.
+#[derive(Debug)]
+pub struct ExecutionOutput {
+    /// Last value in the memory of the program, `boş` when there is no value.
+    pub result: Result<VmObject, KaramelError>,
+    pub stdout: String,
+    pub stderr: String
 }
 
 pub fn get_execution_path<T: Borrow<ExecutionSource>>(source: T) -> ExecutionPathInfo {
@@ -68,8 +82,10 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
         _ => ()
     };
 
-    let mut context: KaramelCompilerContext = KaramelCompilerContext::new();
-    context.execution_path = get_execution_path(&parameters.source);
+    let mut context: KaramelCompilerContext = KaramelCompilerContext::with_config(ContextConfig {
+        script_path: Some(get_execution_path(&parameters.source).path),
+        ..ContextConfig::default()
+    });
     log::debug!("Execution path: {}", context.execution_path.path);
 
     if parameters.return_output {
@@ -87,6 +103,7 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
                     log::error!("Program hata ile sonlandırıldı: {}", error);
                     status.stdout = context.stdout;
                     status.stderr = context.stderr;
+                    status.error  = Some(KaramelError::from(error));
                     
                     status.executed = false;
                     return status
@@ -102,13 +119,14 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
             log::error!("{}", generate_error_message(&data, &error));
             status.stdout = context.stdout;
             status.stderr = context.stderr;
+            status.error  = Some(error);
 
             return status;
         },
         _ => ()
     };
 
-    let syntax = SyntaxParser::new(parser.tokens().to_vec());
+    let syntax = SyntaxParser::new(parser.tokens());
     let ast = match syntax.parse() {
         Ok(ast) => ast,
         Err(error) => {
@@ -116,6 +134,7 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
             log::error!("{}", generate_error_message(&data, &error));
             status.stdout = context.stdout;
             status.stderr = context.stderr;
+            status.error  = Some(error);
 
             return status;
         }
@@ -123,12 +142,16 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
 
     let opcode_compiler = InterpreterCompiler {};
     let execution_status = match opcode_compiler.compile(ast.clone(), &mut context) {
-        Ok(_) => unsafe { run_vm(&mut context, parameters.dump_opcode, parameters.dump_memory) },
+        Ok(_) => {
+            status.diagnostics = context.diagnostics.to_vec();
+            unsafe { run_vm(&mut context, parameters.dump_opcode, parameters.dump_memory) }
+        },
         Err(message) => {
             write_stderr(&context, format!("Program hata ile sonlandırıldı: {}", message));
             log::error!("Program hata ile sonlandırıldı: {}", message);
             status.stdout = context.stdout;
             status.stderr = context.stderr;
+            status.error  = Some(KaramelError::from(message));
 
             return status;
         }
@@ -141,10 +164,17 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
             status.memory_output = Some(memory)
         },
         Err(error) => {
-            write_stderr(&context, format!("Program hata ile sonlandırıldı: {}", error));
-            log::error!("Program hata ile sonlandırıldı: {}", error);
+            let error = KaramelError::from(error).with_backtrace(context.call_stack());
+            let message = match error.backtrace.is_empty() {
+                true => format!("Program hata ile sonlandırıldı: {}", error.error_type),
+                false => format!("Program hata ile sonlandırıldı: {}\r\n{}", error.error_type, generate_backtrace(&error))
+            };
+
+            write_stderr(&context, message.clone());
+            log::error!("{}", message);
             status.stdout = context.stdout;
             status.stderr = context.stderr;
+            status.error  = Some(error);
 
             return status;
         }
@@ -152,7 +182,7 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
 
     log::info!("Program başarıyla çalıştırıldı");
     if parameters.return_opcode {
-        status.opcodes = Some(parser.tokens());
+        status.opcodes = Some(parser.tokens().to_vec());
     }
 
     status.stdout      = context.stdout;
@@ -162,3 +192,25 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
 
     status
 }
+
+/// Runs the code and returns the result with the captured outputs.
+pub fn execute<T: Into<String>>(code: T) -> ExecutionOutput {
+    let status = code_executer(ExecutionParameters {
+        source: ExecutionSource::Code(code.into()),
+        return_opcode: false,
+        return_output: true,
+        dump_opcode: false,
+        dump_memory: false
+    });
+
+    let result = match status.error {
+        Some(error) => Err(error),
+        None => Ok(status.memory_output.and_then(|memory| memory.last().copied()).unwrap_or(EMPTY_OBJECT))
+    };
+
+    ExecutionOutput {
+        result,
+        stdout: status.stdout.map(RefCell::into_inner).unwrap_or_default(),
+        stderr: status.stderr.map(RefCell::into_inner).unwrap_or_default()
+    }
+}
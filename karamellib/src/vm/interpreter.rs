@@ -2,7 +2,7 @@ use crate::compiler::context::KaramelCompilerContext;
 use crate::compiler::scope::Scope;
 use crate::error::KaramelErrorType;
 use crate::logger::write_stdout;
-use crate::{pop, inc_memory_index, dec_memory_index, get_memory_index, karamel_dbg};
+use crate::{pop, pop_clean, inc_memory_index, dec_memory_index, get_memory_index, karamel_dbg};
 use crate::types::{VmObject};
 use crate::compiler::*;
 use std::rc::Rc;
@@ -15,6 +15,7 @@ use std::io::{self, Write};
 use std::ptr;
 use colored::*;
 use crate::buildin::ClassProperty;
+use crate::compiler::function::{FunctionParameter, FunctionReference, FunctionType};
 
 #[cfg(all(feature = "NONONO"))]
 pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerContext, log_update: &mut LogUpdate<W>) {
@@ -41,443 +42,591 @@ pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerC
     }
 }
 
-pub unsafe fn run_vm(context: &mut KaramelCompilerContext, dump_code: bool, dump_memory: bool) -> Result<Vec<VmObject>, KaramelErrorType>
-{
-    #[cfg(any(feature = "liveOpcodeView", feature = "dumpOpcodes"))]
+/// Executes opcodes from the current location until the `Halt` opcode.
+/// Scopes and the stack are used as they are, so it could be called again to execute a function from a native call.
+///
+/// # Safety
+/// Opcode, stack and scope pointers of the context should point to compiled code.
+pub unsafe fn run_opcodes(context: &mut KaramelCompilerContext) -> Result<(), KaramelErrorType> {
+    #[cfg(feature = "liveOpcodeView")]
     let mut log_update = LogUpdate::new(stdout()).unwrap();
-    
-    #[cfg(feature = "dumpMemory")] {
-        context.storages[0].dump();
-    }
-    
-    if dump_code {    
-        let generated = context.opcode_generator.dump(&context.opcodes);
-        context.opcode_dump = Some(generated);
-        //log_update.render(&generated[..]);
-    }
-
-    // Save top stack for main storage
-    let top_stack = context.stack.as_mut_ptr();
 
-    // Move stack pointer to forward. First slots are reserved for variable memories.
-    context.stack_ptr = top_stack.add(context.storages[0].variables.len());
-    context.storages_ptr = context.storages.as_mut_ptr();
-    {
-        context.scopes[context.scope_index] = Scope {
-            location: ptr::null_mut(),
-            call_return_assign_to_temp: false,
-            top_stack: top_stack,
-            constant_ptr: context.storages[0].constants.as_ptr()
-        };
+    let instruction_limit = context.config.instruction_limit;
 
-        loop {
-            let opcode = mem::transmute::<u8, VmOpCode>(*context.opcodes_ptr);
-            #[cfg(all(feature = "liveOpcodeView"))] {
-                dump_opcode(context.opcode_index, context, &mut log_update);
+    loop {
+        if let Some(limit) = instruction_limit {
+            context.instruction_count += 1;
+            if context.instruction_count > limit {
+                return Err(KaramelErrorType::InstructionLimitExceeded(limit));
             }
-            
-            match karamel_dbg_any!(opcode) {
-                VmOpCode::Subraction => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-
-                    karamel_print_level2!("Subraction: {:?} - {:?}", left, right);
-
-                    *context.stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) - karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    inc_memory_index!(context, 1);
-                    dump_data!(context, "result");
-                },
-
-                VmOpCode::Addition => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("Addition: {:?} + {:?}", left, right);
-
-                    *context.stack_ptr = match (&left.deref_clean(), &right.deref_clean()) {
-                        (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value)) => VmObject::from(karamel_dbg!(l_value) + karamel_dbg!(r_value)),
-                        (KaramelPrimative::Text(l_value),    KaramelPrimative::Text(r_value))   => VmObject::from(Rc::new((&**l_value).to_owned() + &**r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::Load => {
-                    let tmp   = *context.opcodes_ptr.offset(1) as usize;
-                    let scope = &mut *context.current_scope;
-                    *context.stack_ptr = karamel_dbg!(*scope.top_stack.offset(tmp as isize));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    karamel_print_level2!("Load: [{:?}]: {:?}", tmp, *context.stack_ptr);
-                    dump_data!(context, "loaded");
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::Constant => {
-                    let tmp   = *context.opcodes_ptr.offset(1) as usize;
-                    let scope = &mut *context.current_scope;        
-                    *context.stack_ptr = karamel_dbg!(*scope.constant_ptr.offset(tmp as isize));        
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    karamel_print_level2!("Constant: [{:?}]: {:?}", tmp, *context.stack_ptr);
-                    dump_data!(context, "constant loaded");
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::Store => {
-                    let tmp = *context.opcodes_ptr.offset(1) as usize;
-                    dec_memory_index!(context, 1);
-                    *(*context.current_scope).top_stack.offset(tmp as isize) = karamel_dbg!(*context.stack_ptr);
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    karamel_print_level2!("Store: [{:?}]: {:?}", tmp, *context.stack_ptr);
-                },
-
-                VmOpCode::CopyToStore => {
-                    let tmp = *context.opcodes_ptr.offset(1) as usize;
-                    *(*context.current_scope).top_stack.offset(tmp as isize) = karamel_dbg!(*context.stack_ptr.sub(1));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    karamel_print_level2!("CopyToStore: [{:?}]: {:?}", tmp, *context.stack_ptr);
-                },
-
-                VmOpCode::FastStore => {
-                    let destination = *context.opcodes_ptr.offset(1) as usize;
-                    let source      = *context.opcodes_ptr.offset(2) as usize;
-                    *(*context.current_scope).top_stack.offset(destination as isize) = karamel_dbg!(*(*context.current_scope).constant_ptr.offset(source as isize));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(2);
-                    karamel_print_level2!("FastStore: {:?}: {:?} => {:?}", *(*context.current_scope).top_stack.offset(destination as isize), source, destination);
-                },
-
-                VmOpCode::Not => {
-                    *context.stack_ptr.sub(1) = VmObject::from(!(*context.stack_ptr.sub(1)).deref_clean().is_true());
-                    dump_data!(context, "result");
-                    karamel_print_level2!("Not: {:?}", *context.stack_ptr.sub(1));
-                },
-
-                VmOpCode::Dublicate => {
-                    *context.stack_ptr = karamel_dbg!(*context.stack_ptr.sub(1));
-                    karamel_print_level2!("Dublicate: {:?}", *context.stack_ptr);
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::And => {
-                    let left  = pop!(context, "left");
-                    let right = pop!(context, "right");
-                    karamel_print_level2!("And: {:?} && {:?}", left, right);
-
-                    *context.stack_ptr = VmObject::from(karamel_dbg!(left.is_true()) && karamel_dbg!(right.is_true()));
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::Or => {
-                    let left  = pop!(context, "left");
-                    let right = pop!(context, "right");
-                    karamel_print_level2!("Or: {:?} || {:?}", left, right);
-
-                    *context.stack_ptr = VmObject::from(karamel_dbg!(left.is_true()) || karamel_dbg!(right.is_true()));
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::Multiply => {
-                    let right = pop!(context, "right");
-                    let left  = pop!(context, "left");
-                    karamel_print_level2!("Multiply: {:?} * {:?}", left, right);
-
-                    *context.stack_ptr = match (&*left, &*right) {
-                        (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value))   => VmObject::from(*l_value * *r_value),
-                        (KaramelPrimative::Text(l_value),    KaramelPrimative::Number(r_value))   => VmObject::from((*l_value).repeat((*r_value) as usize)),
-                        _ => EMPTY_OBJECT
-                    };
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                },
+        }
 
-                VmOpCode::Division => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("Division: {:?} / {:?}", left, right);
+        let opcode = mem::transmute::<u8, VmOpCode>(*context.opcodes_ptr);
+        #[cfg(all(feature = "liveOpcodeView"))] {
+            dump_opcode(context.opcode_index, context, &mut log_update);
+        }
+        
+        match karamel_dbg_any!(opcode) {
+            VmOpCode::Subraction => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+
+                karamel_print_level2!("Subraction: {:?} - {:?}", left, right);
+
+                *context.stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) - karamel_dbg!(r_value)),
+                    _ => EMPTY_OBJECT
+                };
+                inc_memory_index!(context, 1);
+                dump_data!(context, "result");
+            },
+
+            VmOpCode::Addition => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("Addition: {:?} + {:?}", left, right);
+
+                /* Both sides could be the same text (a + a), new text is created before the slot is overwritten */
+                let result = match (&left.deref_clean(), &right.deref_clean()) {
+                    (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value)) => VmObject::from(karamel_dbg!(l_value) + karamel_dbg!(r_value)),
+                    (KaramelPrimative::Text(l_value),    KaramelPrimative::Text(r_value))   => VmObject::from(Rc::new((&**l_value).to_owned() + &**r_value)),
+                    _ => EMPTY_OBJECT
+                };
+                *context.stack_ptr = result;
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Load => {
+                let tmp   = *context.opcodes_ptr.offset(1) as usize;
+                let scope = &mut *context.current_scope;
+                *context.stack_ptr = karamel_dbg!(*scope.top_stack.offset(tmp as isize));
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                karamel_print_level2!("Load: [{:?}]: {:?}", tmp, *context.stack_ptr);
+                dump_data!(context, "loaded");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Constant => {
+                let tmp   = *context.opcodes_ptr.offset(1) as usize;
+                let scope = &mut *context.current_scope;        
+                *context.stack_ptr = karamel_dbg!(*scope.constant_ptr.offset(tmp as isize));        
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                karamel_print_level2!("Constant: [{:?}]: {:?}", tmp, *context.stack_ptr);
+                dump_data!(context, "constant loaded");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Store => {
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                dec_memory_index!(context, 1);
+                *(*context.current_scope).top_stack.offset(tmp as isize) = karamel_dbg!(*context.stack_ptr);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                karamel_print_level2!("Store: [{:?}]: {:?}", tmp, *context.stack_ptr);
+            },
+
+            VmOpCode::CopyToStore => {
+                /* Stack pointer is not moved, the value belongs to the enclosing expression */
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                *(*context.current_scope).top_stack.offset(tmp as isize) = karamel_dbg!(*context.stack_ptr.sub(1));
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                karamel_print_level2!("CopyToStore: [{:?}]: {:?}", tmp, *context.stack_ptr);
+            },
+
+            VmOpCode::FastStore => {
+                let destination = *context.opcodes_ptr.offset(1) as usize;
+                let source      = *context.opcodes_ptr.offset(2) as usize;
+                *(*context.current_scope).top_stack.offset(destination as isize) = karamel_dbg!(*(*context.current_scope).constant_ptr.offset(source as isize));
+                context.opcodes_ptr = context.opcodes_ptr.offset(2);
+                karamel_print_level2!("FastStore: {:?}: {:?} => {:?}", *(*context.current_scope).top_stack.offset(destination as isize), source, destination);
+            },
+
+            VmOpCode::Not => {
+                *context.stack_ptr.sub(1) = VmObject::from(!(*context.stack_ptr.sub(1)).deref_clean().is_true());
+                dump_data!(context, "result");
+                karamel_print_level2!("Not: {:?}", *context.stack_ptr.sub(1));
+            },
+
+            VmOpCode::Duplicate => {
+                *context.stack_ptr = karamel_dbg!(*context.stack_ptr.sub(1));
+                karamel_print_level2!("Duplicate: {:?}", *context.stack_ptr);
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Pop => {
+                dec_memory_index!(context, 1);
+                karamel_print_level2!("Pop: {:?}", *context.stack_ptr);
+            },
+
+            VmOpCode::Swap => {
+                std::ptr::swap(context.stack_ptr.sub(1), context.stack_ptr.sub(2));
+                karamel_print_level2!("Swap: {:?} <=> {:?}", *context.stack_ptr.sub(1), *context.stack_ptr.sub(2));
+            },
+
+            VmOpCode::And => {
+                let right = pop_raw!(context, "right");
+                let left  = pop_raw!(context, "left");
+                karamel_print_level2!("And: {:?} && {:?}", left, right);
+
+                *context.stack_ptr = match karamel_dbg!(left.deref_clean().is_true()) {
+                    true => right,
+                    false => left
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Or => {
+                let right = pop_raw!(context, "right");
+                let left  = pop_raw!(context, "left");
+                karamel_print_level2!("Or: {:?} || {:?}", left, right);
+
+                *context.stack_ptr = match karamel_dbg!(left.deref_clean().is_true()) {
+                    true => left,
+                    false => right
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Multiply => {
+                let right = pop_clean!(context, "right");
+                let left  = pop_clean!(context, "left");
+                karamel_print_level2!("Multiply: {:?} * {:?}", left, right);
+
+                let result = match (&left, &right) {
+                    (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value))   => VmObject::from(*l_value * *r_value),
+                    (KaramelPrimative::Text(l_value),    KaramelPrimative::Number(r_value))   => VmObject::from((*l_value).repeat((*r_value) as usize)),
+                    _ => EMPTY_OBJECT
+                };
+                *context.stack_ptr = result;
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Division => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("Division: {:?} / {:?}", left, right);
+
+                let calculation = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => (l_value / r_value),
+                    _ => std::f64::NAN
+                };
+
+                *context.stack_ptr = if calculation.is_nan() {
+                    EMPTY_OBJECT
+                }
+                else {
+                    VmObject::from(calculation)
+                };
+
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Modulo => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("Modulo: {:?} % {:?}", left, right);
+
+                let calculation = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => karamel_dbg!(l_value) % karamel_dbg!(r_value),
+                    _ => std::f64::NAN
+                };
+
+                *context.stack_ptr = if calculation.is_nan() {
+                    EMPTY_OBJECT
+                }
+                else {
+                    VmObject::from(calculation)
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Exponent => {
+                let right = pop_clean!(context, "right");
+                let left  = pop_clean!(context, "left");
+                karamel_print_level2!("Exponent: {:?} ** {:?}", left, right);
+
+                *context.stack_ptr = match (&left, &right) {
+                    (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value))   => VmObject::from(l_value.powf(*r_value)),
+                    _ => EMPTY_OBJECT
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Equal => {
+                let right = pop_clean!(context, "right");
+                let left  = pop_clean!(context, "left");
+                karamel_print_level2!("Equal: {:?} == {:?}", left, right);
+                
+                *context.stack_ptr = VmObject::from(karamel_dbg!(left) == karamel_dbg!(right));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
 
-                    let calculation = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => (l_value / r_value),
-                        _ => std::f64::NAN
-                    };
 
-                    *context.stack_ptr = if calculation.is_nan() {
-                        EMPTY_OBJECT
+            VmOpCode::NotEqual => {
+                let right = pop_clean!(context, "right");
+                let left  = pop_clean!(context, "left");
+                karamel_print_level2!("NotEqual: {:?} != {:?}", left, right);
+                
+                *context.stack_ptr = VmObject::from(karamel_dbg!(left) != karamel_dbg!(right));
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::GreaterThan => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("GreaterThan: {:?} > {:?}", left, right);
+                
+                *context.stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) > karamel_dbg!(r_value)),
+                    _ => EMPTY_OBJECT
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::GreaterEqualThan => {
+                let right = pop_raw!(context, "right");
+                let left = pop_raw!(context, "left");
+                karamel_print_level2!("GreaterEqualThan {:?} >= {:?}", left, right);
+                
+                *context.stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) >= karamel_dbg!(r_value)),
+                    _ => EMPTY_OBJECT
+                };
+                dump_data!(context, "result");
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Call => {
+                let func_location   = *context.opcodes_ptr.offset(1) as usize;
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                
+                let value = (*(*context.current_scope).constant_ptr.offset(func_location as isize)).deref();
+
+                karamel_print_level2!("Call: {:?}", value);
+                if let KaramelPrimative::Function(reference, _) = karamel_dbg!(&*value) {
+                    reference.execute(context, None)?;
+                }
+                else {
+                    return Err(KaramelErrorType::NotCallable(value.clone()));
+                }
+            },
+
+            VmOpCode::CallStack => {
+                let function = pop_raw!(context, "function");
+                let value =  function.deref();
+                karamel_print_level2!("CallStack {:?}", value);
+                
+                match &*value {
+                    KaramelPrimative::Function(reference, base) => reference.execute(context, *base)?,
+                    _ => {
+                        log::debug!("{:?} not callable", &*function.deref());
+                    return Err(KaramelErrorType::NotCallable(value.clone()));
                     }
-                    else {
-                        VmObject::from(calculation)
+                };
+            },
+
+            VmOpCode::CallSpread => {
+                let function = pop_raw!(context, "function");
+                let value =  function.deref();
+                karamel_print_level2!("CallSpread {:?}", value);
+
+                let (reference, base) = match &*value {
+                    KaramelPrimative::Function(reference, base) => (reference.clone(), *base),
+                    _ => return Err(KaramelErrorType::NotCallable(value.clone()))
+                };
+
+                let list_size                  = *context.opcodes_ptr.offset(1) as usize;
+                let call_return_assign_to_temp = *context.opcodes_ptr.offset(2) != 0;
+                let mut arguments              = Vec::new();
+
+                for index in (1..=list_size).rev() {
+                    match &*(*context.stack_ptr.sub(index)).deref() {
+                        KaramelPrimative::List(list) => arguments.extend(list.borrow().iter().copied()),
+                        _ => return Err(KaramelErrorType::SpreadArgumentNotValid)
                     };
+                }
+                dec_memory_index!(context, list_size);
 
-                    inc_memory_index!(context, 1);
-                },
+                if arguments.len() > u8::MAX as usize {
+                    return Err(KaramelErrorType::SpreadArgumentNotValid);
+                }
 
-                VmOpCode::Module => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("Module: {:?} / {:?}", left, right);
+                if get_memory_index!(context) as usize + arguments.len() > context.stack.len() {
+                    return Err(KaramelErrorType::StackOverflow(reference.name.to_string()));
+                }
 
-                    *context.stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) % karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    dump_data!(context, "result");
+                for argument in arguments.iter() {
+                    *context.stack_ptr = *argument;
                     inc_memory_index!(context, 1);
-                },
+                }
 
-                VmOpCode::Equal => {
-                    let right = pop!(context, "right");
-                    let left  = pop!(context, "left");
-                    karamel_print_level2!("Equal: {:?} == {:?}", left, right);
-                    
-                    *context.stack_ptr = VmObject::from(karamel_dbg!(left) == karamel_dbg!(right));
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                },
+                reference.execute_with_arguments(context, base, arguments.len() as u8, call_return_assign_to_temp)?;
+            },
 
+            VmOpCode::Return => {
+                let return_value               = *context.stack_ptr.sub(1);
+                context.opcodes_ptr            = (*context.current_scope).location;
+                let call_return_assign_to_temp = (*context.current_scope).call_return_assign_to_temp;
+                context.scope_index           -= 1;
 
-                VmOpCode::NotEqual => {
-                    let right = pop!(context, "right");
-                    let left  = pop!(context, "left");
-                    karamel_print_level2!("NotEqual: {:?} != {:?}", left, right);
-                    
-                    *context.stack_ptr = VmObject::from(karamel_dbg!(left) != karamel_dbg!(right));
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                },
+                context.stack_ptr = (*context.current_scope).top_stack;
+                context.current_scope          = context.scopes_ptr.add(context.scope_index);              
 
-                VmOpCode::GreaterThan => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("GreaterThan: {:?} > {:?}", left, right);
-                    
-                    *context.stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) > karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    dump_data!(context, "result");
+                if call_return_assign_to_temp {
+                    *context.stack_ptr = return_value;
+                    karamel_print_level2!("Return [{:?}] {:?}", get_memory_index!(context), *context.stack_ptr);
                     inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::GreaterEqualThan => {
-                    let right = pop_raw!(context, "right");
-                    let left = pop_raw!(context, "left");
-                    karamel_print_level2!("GreaterEqualThan {:?} >= {:?}", left, right);
-                    
-                    *context.stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) >= karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    dump_data!(context, "result");
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::Call => {
-                    let func_location   = *context.opcodes_ptr.offset(1) as usize;
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    
-                    let value = (*(*context.current_scope).constant_ptr.offset(func_location as isize)).deref();
-
-                    karamel_print_level2!("Call: {:?}", value);
-                    if let KaramelPrimative::Function(reference, _) = karamel_dbg!(&*value) {
-                        reference.execute(context, None)?;
-                    }
-                    else {
-                        return Err(KaramelErrorType::NotCallable(value.clone()));
-                    }
-                },
-
-                VmOpCode::CallStack => {
-                    let function = pop_raw!(context, "function");
-                    let value =  function.deref();
-                    karamel_print_level2!("CallStack {:?}", value);
-                    
-                    match &*value {
-                        KaramelPrimative::Function(reference, base) => reference.execute(context, *base)?,
-                        _ => {
-                            log::debug!("{:?} not callable", &*function.deref());
-                        return Err(KaramelErrorType::NotCallable(value.clone()));
+                } else {
+                    karamel_print_level2!("Return");
+                }
+            },
+
+            VmOpCode::Increment => {
+                karamel_print_level2!("Increment");
+                *context.stack_ptr.sub(1) = match (*context.stack_ptr.sub(1)).as_number() {
+                    Some(value) => VmObject::from(karamel_dbg!(value + 1 as f64)),
+                    _ => EMPTY_OBJECT
+                };
+            },
+
+            VmOpCode::Decrement => {
+                karamel_print_level2!("Increment");
+                *context.stack_ptr.sub(1) = match (*context.stack_ptr.sub(1)).as_number() {
+                    Some(value) => VmObject::from(value - 1 as f64),
+                    _ => EMPTY_OBJECT
+                };
+            },
+
+            VmOpCode::Init => {
+                let init_type = *context.opcodes_ptr.offset(1) as usize;
+                let total_item = *context.opcodes_ptr.offset(2) as usize;
+                karamel_print_level2!("Init: {:?} {:?}", init_type, total_item);
+
+                *context.stack_ptr = match init_type {
+                    // Dict
+                    0 => {
+                        let mut items  = Vec::with_capacity(total_item);
+    
+                        for _ in 0..total_item {
+                            let value = pop_raw!(context, "value");
+                            let key   = match &*pop!(context, "key") {
+                                KaramelPrimative::Text(text) => text.to_string(),
+                                _ => return Err(KaramelErrorType::DictionaryKeyNotValid)
+                            };
+                            items.push((key, value));
                         }
-                    };
-                },
-
-                VmOpCode::Return => {
-                    let return_value               = *context.stack_ptr.sub(1);
-                    context.opcodes_ptr            = (*context.current_scope).location;
-                    let call_return_assign_to_temp = (*context.current_scope).call_return_assign_to_temp;
-                    context.scope_index           -= 1;
-
-                    context.stack_ptr = (*context.current_scope).top_stack;
-                    context.current_scope          = context.scopes_ptr.add(context.scope_index);              
-
-                    if call_return_assign_to_temp {
-                        *context.stack_ptr = return_value;
-                        karamel_print_level2!("Return [{:?}] {:?}", get_memory_index!(context), *context.stack_ptr);
-                        inc_memory_index!(context, 1);
-                    } else {
-                        karamel_print_level2!("Return");
-                    }
-                },
-
-                VmOpCode::Increment => {
-                    karamel_print_level2!("Increment");
-                    *context.stack_ptr.sub(1) = match (*context.stack_ptr.sub(1)).as_number() {
-                        Some(value) => VmObject::from(karamel_dbg!(value + 1 as f64)),
-                        _ => EMPTY_OBJECT
-                    };
-                },
 
-                VmOpCode::Decrement => {
-                    karamel_print_level2!("Increment");
-                    *context.stack_ptr.sub(1) = match (*context.stack_ptr.sub(1)).as_number() {
-                        Some(value) => VmObject::from(value - 1 as f64),
-                        _ => EMPTY_OBJECT
-                    };
-                },
+                        /* Items are popped from the last one, same keys keep the last value */
+                        VmObject::from(items.into_iter().rev().collect::<HashMap<_, _>>())
+                    },
 
-                VmOpCode::Init => {
-                    let init_type = *context.opcodes_ptr.offset(1) as usize;
-                    let total_item = *context.opcodes_ptr.offset(2) as usize;
-                    karamel_print_level2!("Init: {:?} {:?}", init_type, total_item);
+                    // List
+                    1 => {
+                        let mut list = Vec::with_capacity(total_item.into());
 
-                    *context.stack_ptr = match init_type {
-                        // Dict
-                        0 => {
-                            let mut dict   = HashMap::new();
-        
-                            for _ in 0..total_item {
-                                let value = pop_raw!(context, "value");
-                                let key   = pop!(context, "key");
-                                
-                                dict.insert(key.get_text(), value);
-                            }
+                        for i in 0..total_item {
+                            list.push(pop_raw!(context, i));
+                        }
 
-                            VmObject::from(dict)
-                        },
+                        /* Items are evaluated in order, so the last item is on the top of the stack */
+                        list.reverse();
+                        VmObject::from(list)
+                    },
+                     _ => return Err(KaramelErrorType::GeneralError("Geçersiz yükleme tipi".to_string()))
+                };
+                
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(2);
+            },
 
-                        // List
-                        1 => {
-                            let mut list = Vec::with_capacity(total_item.into());
+            VmOpCode::Compare => {
+                let condition = pop_raw!(context, "condition");
+                karamel_print_level2!("Compare: {:?}", condition);
 
-                            for i in 0..total_item {
-                                list.push(pop_raw!(context, i));
-                            }
-                            
-                            VmObject::from(list)
-                        },
-                         _ => return Err(KaramelErrorType::GeneralError("Geçersiz yükleme tipi".to_string()))
-                    };
-                    
-                    inc_memory_index!(context, 1);
+                if condition.deref_clean().is_true() {
                     context.opcodes_ptr = context.opcodes_ptr.offset(2);
-                },
-
-                VmOpCode::Compare => {
-                    let condition = pop_raw!(context, "condition");
-                    karamel_print_level2!("Compare: {:?}", condition);
-
-                    let status = match &condition.deref_clean() {
-                        KaramelPrimative::Empty => false,
-                        KaramelPrimative::Bool(l_value) => *l_value,
-                        KaramelPrimative::Number(l_value) => *l_value > 0.0,
-                        KaramelPrimative::Text(l_value) => !(*l_value).is_empty(),
-                        _ => false
-                    };
-
-                    if status {
-                        context.opcodes_ptr = context.opcodes_ptr.offset(2);
-                    }
-                    else {
-                        let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
-                        context.opcodes_ptr = context.opcodes_ptr.offset(location as isize);
-                        continue;
-                    }
-                },
-
-                VmOpCode::Jump => {
-                    let location = ((*context.opcodes_ptr.offset(2)  as u16 * 256) + *context.opcodes_ptr.offset(1)  as u16) as usize;
-                    karamel_print_level2!("Jump: {:?}", location);
-                    context.opcodes_ptr = context.opcodes.as_mut_ptr().offset(location as isize);
+                }
+                else {
+                    let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
+                    context.opcodes_ptr = context.opcodes_ptr.offset(location as isize);
                     continue;
-                },
-                
-                VmOpCode::SetItem => {
-                    let assign_item  = pop_raw!(context, "assign_item");
-                    let indexer = pop!(context, "indexer");
-                    let raw_object = pop_raw!(context, "raw_object");
-                    let object  = raw_object.deref();
-                    karamel_print_level2!("GetItem: object={:?}, indexer={:?}, item={:?}", object, indexer, assign_item);
-
-                    // todo: change all those codes with setter implementation
-                    match &*object {
-                        KaramelPrimative::List(value) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Number(number) => *number as usize,
-                                _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone()))
-                            };
-
-                            value.borrow_mut()[indexer_value] = assign_item;
-                        },
-                        KaramelPrimative::Dict(value) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Text(text) => &*text,
-                                _ => return Err(KaramelErrorType::IndexerMustBeString(indexer.clone()))
-                            };
-
-                            value.borrow_mut().insert(indexer_value.to_string(), assign_item);
-                        },
-                        KaramelPrimative::Text(_) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Number(number) => *number,
-                                _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone()))
-                            };
-
-                            match context.get_class(&object).get_setter() {
-                                Some(function) => function(raw_object, indexer_value, assign_item)?,
-                                _ => EMPTY_OBJECT
-                            };
-                        },
-                        
-                        _ => ()
-                    };
-                },
-
-                VmOpCode::GetItem => {
-                    let indexer = pop!(context, "indexer");
-                    let raw_object  = pop_raw!(context, "raw_object");
-                    let object = &*raw_object.deref();
-                    karamel_print_level2!("GetItem: object={:?}, indexer={:?}", object, indexer);
-
-                    *context.stack_ptr = match &*indexer {
-                        KaramelPrimative::Text(text) => {
-                             match context.get_class(object).get_element(Some(raw_object), text.clone()) {
+                }
+            },
+
+            VmOpCode::Jump => {
+                let location = ((*context.opcodes_ptr.offset(2)  as u16 * 256) + *context.opcodes_ptr.offset(1)  as u16) as usize;
+                karamel_print_level2!("Jump: {:?}", location);
+                context.opcodes_ptr = context.opcodes.as_mut_ptr().offset(location as isize);
+                continue;
+            },
+            
+            VmOpCode::SetItem => {
+                let assign_item  = pop_raw!(context, "assign_item");
+                let indexer = pop!(context, "indexer");
+                let raw_object = pop_raw!(context, "raw_object");
+                let object  = raw_object.deref();
+                karamel_print_level2!("GetItem: object={:?}, indexer={:?}, item={:?}", object, indexer, assign_item);
+
+                // todo: change all those codes with setter implementation
+                match &*object {
+                    KaramelPrimative::List(value) => {
+                        let indexer_value = match &*indexer {
+                            KaramelPrimative::Number(number) => *number as usize,
+                            _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone()))
+                        };
+
+                        value.borrow_mut()[indexer_value] = assign_item;
+                    },
+                    KaramelPrimative::Dict(value) => {
+                        let indexer_value = match &*indexer {
+                            KaramelPrimative::Text(text) => &*text,
+                            _ => return Err(KaramelErrorType::IndexerMustBeString(indexer.clone()))
+                        };
+
+                        value.borrow_mut().insert(indexer_value.to_string(), assign_item);
+                    },
+                    KaramelPrimative::Text(_) => {
+                        let indexer_value = match &*indexer {
+                            KaramelPrimative::Number(number) => *number,
+                            _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone()))
+                        };
+
+                        match context.get_class(&object).get_setter() {
+                            Some(function) => function(raw_object, indexer_value, assign_item)?,
+                            _ => EMPTY_OBJECT
+                        };
+                    },
+                    
+                    _ => ()
+                };
+            },
+
+            VmOpCode::GetItem => {
+                let indexer = pop!(context, "indexer");
+                let raw_object  = pop_raw!(context, "raw_object");
+                let object = &*raw_object.deref();
+                karamel_print_level2!("GetItem: object={:?}, indexer={:?}", object, indexer);
+
+                *context.stack_ptr = match &*indexer {
+                    KaramelPrimative::Text(text) => {
+                        let location = context.opcodes_ptr.offset_from(context.opcodes_top_ptr) as usize;
+                        match context.get_cached_method(location, object, text) {
+                            Some(function) => VmObject::from(Rc::new(KaramelPrimative::Function(function, Some(raw_object)))),
+                            None => match context.get_class(object).get_element(Some(raw_object), text.clone()) {
                                 Some(element) => match element {
-                                    ClassProperty::Function(function) => VmObject::from(Rc::new(KaramelPrimative::Function(function.clone(), Some(raw_object)))),
+                                    ClassProperty::Function(function) => {
+                                        context.set_cached_method(location, object, text.clone(), function.clone());
+                                        VmObject::from(Rc::new(KaramelPrimative::Function(function.clone(), Some(raw_object))))
+                                    },
                                     ClassProperty::Field(field) => VmObject::from(field.clone())
                                 },
                                 _ => EMPTY_OBJECT
                             }
-                        },
-                        KaramelPrimative::Number(index) => match context.get_class(object).get_getter() {
-                            Some(function) => function(raw_object, *index)?,
-                            _ => EMPTY_OBJECT
                         }
+                    },
+                    KaramelPrimative::Number(index) => match context.get_class(object).get_getter() {
+                        Some(function) => function(raw_object, *index)?,
                         _ => EMPTY_OBJECT
-                    };
+                    }
+                    _ => EMPTY_OBJECT
+                };
 
-                    inc_memory_index!(context, 1);
-                },
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::Halt => {
-                    karamel_print_level2!("Halt");
-                    break;
-                },
-            }
+            VmOpCode::Halt => {
+                karamel_print_level2!("Halt");
+                break;
+            },
+        }
+
+        context.opcodes_ptr = context.opcodes_ptr.offset(1);
+    }
+    Ok(())
+}
+
+/// Executes a function value from a native function and returns the result of it.
+/// Script functions are executed with a nested `run_opcodes`, the caller's location and stack are restored after the call.
+///
+/// # Safety
+/// Should only be called while `run_vm` is executing the context.
+pub unsafe fn call_function(context: &mut KaramelCompilerContext, callable: VmObject, arguments: &[VmObject]) -> Result<VmObject, KaramelErrorType> {
+    let value = callable.deref();
+    let (reference, base) = match &*value {
+        KaramelPrimative::Function(reference, base) => (reference.clone(), *base),
+        _ => return Err(KaramelErrorType::NotCallable(value.clone()))
+    };
+
+    let opcodes_ptr = context.opcodes_ptr;
+    let stack_ptr   = context.stack_ptr;
+
+    for argument in arguments {
+        *context.stack_ptr = *argument;
+        inc_memory_index!(context, 1);
+    }
 
-            context.opcodes_ptr = context.opcodes_ptr.offset(1);
+    let result = match reference.callback {
+        FunctionType::Native(func) => {
+            let context_ptr: *mut KaramelCompilerContext = context;
+            let parameter = FunctionParameter::new(&context.stack, base, get_memory_index!(context) as usize, arguments.len() as u8, &context.stdout, &context.stderr).with_context(context_ptr);
+            func(parameter)
+        },
+        FunctionType::Opcode => {
+            /* Main code ends with Halt, returning to the opcode before it stops the nested execution */
+            let return_location = context.opcodes_top_ptr.add(context.opcodes.len() - 2);
+            FunctionReference::enter_opcode_function(&reference, context, arguments.len() as u8, true, return_location)
+                .and_then(|_| {
+                    /* Function starts with the argument size, first opcode comes after it */
+                    context.opcodes_ptr = context.opcodes_ptr.add(1);
+                    run_opcodes(context)
+                })
+                .map(|_| *context.stack_ptr.sub(1))
         }
+    };
+
+    context.opcodes_ptr = opcodes_ptr;
+    context.stack_ptr   = stack_ptr;
+    result
+}
+
+pub unsafe fn run_vm(context: &mut KaramelCompilerContext, dump_code: bool, dump_memory: bool) -> Result<Vec<VmObject>, KaramelErrorType>
+{
+    #[cfg(feature = "dumpMemory")] {
+        context.storages[0].dump();
+    }
+    
+    if dump_code {    
+        let generated = context.opcode_generator.dump(&context.opcodes);
+        context.opcode_dump = Some(generated);
+        //log_update.render(&generated[..]);
+    }
+
+    // Save top stack for main storage
+    let top_stack = context.stack.as_mut_ptr();
+
+    // Move stack pointer to forward. First slots are reserved for variable memories.
+    context.stack_ptr = top_stack.add(context.storages[0].variables.len());
+    context.storages_ptr = context.storages.as_mut_ptr();
+    context.instruction_count = 0;
+    {
+        context.scopes[context.scope_index] = Scope {
+            location: ptr::null_mut(),
+            call_return_assign_to_temp: false,
+            top_stack: top_stack,
+            constant_ptr: context.storages[0].constants.as_ptr(),
+            function: ptr::null(),
+            call_location: 0
+        };
+
+        run_opcodes(context)?;
         
         if dump_memory {
             let dump = context.storages[0].dump();
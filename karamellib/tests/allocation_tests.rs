@@ -0,0 +1,67 @@
+extern crate karamellib;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/* Counts the allocations of the current thread, tests are running on different threads */
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::karamellib::parser::*;
+    use crate::karamellib::compiler::*;
+    use crate::karamellib::vm::*;
+    use crate::karamellib::syntax::*;
+
+    fn vm_allocations(code: &str) -> usize {
+        let mut parser = Parser::new(code);
+        parser.parse().unwrap();
+
+        let syntax = SyntaxParser::new(parser.tokens().to_vec());
+        let ast = syntax.parse().unwrap();
+
+        let mut context = KaramelCompilerContext::new();
+        let opcode_compiler = InterpreterCompiler {};
+        opcode_compiler.compile(ast, &mut context).unwrap();
+
+        let start = super::allocations();
+        unsafe { interpreter::run_vm(&mut context, false, false).unwrap() };
+        super::allocations() - start
+    }
+
+    #[test]
+    fn arithmetic_allocations() {
+        let allocations = vm_allocations(r#"
+a = 1
+b = doğru
+sayaç = 0
+döngü sayaç != 1000:
+    a = a * 1
+    b = b ve a == 1
+    sayaç += 1
+"#);
+        assert!(allocations < 10, "{} allocations", allocations);
+    }
+}
@@ -6,7 +6,8 @@ mod tests {
     use crate::karamellib::types::*;
     use crate::karamellib::syntax::SyntaxParser;
     use crate::karamellib::compiler::value::KaramelPrimative;
-    use crate::karamellib::compiler::ast::KaramelAstType;
+    use crate::karamellib::compiler::ast::{KaramelAstPosition, KaramelAstType, KaramelDictItem};
+    use crate::karamellib::error::*;
     use std::rc::Rc;
 
     #[warn(unused_macros)]
@@ -29,7 +30,8 @@ mod tests {
     test_compare!(assignment_1, "erhan = 2020", Ok(Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2020.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2020.0)))),
+        position: KaramelAstPosition::default()
     })));
 
     test_compare!(assignment_2, "erhan = ('erhan' * 2)", Ok(Rc::new(KaramelAstType::Assignment {
@@ -39,6 +41,48 @@ mod tests {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("erhan".to_string()))))),
             operator: KaramelOperatorType::Multiplication, 
             right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
-        })
+        }),
+        position: KaramelAstPosition::default()
     })));
-}
\ No newline at end of file
+
+    test_compare!(assignment_3, "erhan = 1, 2", Err(KaramelError::new(0, 9, KaramelErrorType::UnexpectedComma)));
+    test_compare!(assignment_4, "erhan = (1, 2)", Err(KaramelError::new(0, 11, KaramelErrorType::UnexpectedComma)));
+    test_compare!(assignment_5, "erhan = [1, 2]", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::List([
+            Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+            Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+        ].to_vec())),
+        position: KaramelAstPosition::default()
+    })));
+    test_compare!(assignment_6, "erhan = {'a': 1, 'b': 2}", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::Dict([
+            Rc::new(KaramelDictItem {
+                key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("a".to_string()))))),
+                value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+            }),
+            Rc::new(KaramelDictItem {
+                key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("b".to_string()))))),
+                value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            })
+        ].to_vec())),
+        position: KaramelAstPosition::default()
+    })));
+    test_compare!(assignment_7, "erhan = topla(1, 2)", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::FuncCall {
+            func_name_expression: Rc::new(KaramelAstType::Symbol("topla".to_string())),
+            arguments: [
+                Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+                Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            ].to_vec(),
+            assign_to_temp: std::cell::Cell::new(true),
+            position: KaramelAstPosition::default()
+        }),
+        position: KaramelAstPosition::default()
+    })));
+}
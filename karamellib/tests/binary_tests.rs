@@ -7,6 +7,7 @@ mod tests {
     use crate::karamellib::parser::*;
     use crate::karamellib::types::*;
     use crate::karamellib::syntax::*;
+    use crate::karamellib::syntax::binary::*;
     use crate::karamellib::compiler::value::KaramelPrimative;
     use crate::karamellib::compiler::ast::KaramelAstType;
     use std::rc::Rc;
@@ -123,12 +124,16 @@ mod tests {
     test_compare!(multiply_divide_8, "1/", Err(KaramelError {
         error_type: KaramelErrorType::RightSideOfExpressionNotFound,
         column: 2,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(multiply_divide_9, "/1", Err(KaramelError {
         error_type: KaramelErrorType::SyntaxError,
         column: 0,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
 
     test_compare!(modulo_1, "10 mod 10", Ok(Rc::new(KaramelAstType::Binary {
@@ -162,4 +167,93 @@ mod tests {
         operator: KaramelOperatorType::Modulo, 
         right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
     })));
-}
\ No newline at end of file
+    test_compare!(exponent_1, "2 ** 8", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))), 
+        operator: KaramelOperatorType::Exponent, 
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(8.0))))
+    })));
+    test_compare!(exponent_2, "2 ** 3 ** 2", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))), 
+        operator: KaramelOperatorType::Exponent, 
+        right: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+            operator: KaramelOperatorType::Exponent, 
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+        })
+    })));
+    test_compare!(exponent_3, "2 * 3 ** 2", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))), 
+        operator: KaramelOperatorType::Multiplication, 
+        right: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+            operator: KaramelOperatorType::Exponent, 
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+        })
+    })));
+    test_compare!(precedence_1, "2 + 3 * 4 ** 2", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+        operator: KaramelOperatorType::Addition,
+        right: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+            operator: KaramelOperatorType::Multiplication,
+            right: Rc::new(KaramelAstType::Binary {
+                left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(4.0)))),
+                operator: KaramelOperatorType::Exponent,
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            })
+        })
+    })));
+    test_compare!(precedence_2, "10 - 2 - 3", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+            operator: KaramelOperatorType::Subtraction,
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+        }),
+        operator: KaramelOperatorType::Subtraction,
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0))))
+    })));
+    test_compare!(precedence_3, "1 + 2 < 4 ve 5 == 5", Ok(Rc::new(KaramelAstType::Control {
+        left: Rc::new(KaramelAstType::Control {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(4.0)))),
+            operator: KaramelOperatorType::GreaterThan,
+            right: Rc::new(KaramelAstType::Binary {
+                left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+                operator: KaramelOperatorType::Addition,
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            })
+        }),
+        operator: KaramelOperatorType::And,
+        right: Rc::new(KaramelAstType::Control {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(5.0)))),
+            operator: KaramelOperatorType::Equal,
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(5.0))))
+        })
+    })));
+    test_compare!(precedence_4, "2 * 3 +", Err(KaramelError {
+        error_type: KaramelErrorType::RightSideOfExpressionNotFound,
+        column: 7,
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
+    }));
+
+    #[test]
+    fn precedence_table() {
+        for operator in OPERATOR_PRECEDENCES.iter() {
+            assert_eq!(OPERATOR_PRECEDENCES.iter().filter(|item| item.operator == operator.operator).count(), 1);
+        }
+
+        let precedence = |operator| get_operator_precedence(operator).unwrap().precedence;
+        assert!(precedence(KaramelOperatorType::Or) < precedence(KaramelOperatorType::And));
+        assert!(precedence(KaramelOperatorType::Addition) < precedence(KaramelOperatorType::Multiplication));
+        assert!(precedence(KaramelOperatorType::Multiplication) < precedence(KaramelOperatorType::Exponent));
+        assert_eq!(get_operator_precedence(KaramelOperatorType::Exponent).unwrap().associativity, Associativity::Right);
+        assert!(get_operator_precedence(KaramelOperatorType::Assign).is_none());
+    }
+    test_compare!(add_subtract_6, "(10 +
+    20)", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
+        operator: KaramelOperatorType::Addition, 
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(20.0))))
+    })));
+}
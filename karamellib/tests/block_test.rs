@@ -6,7 +6,7 @@ mod tests {
     use crate::karamellib::types::*;
     use crate::karamellib::syntax::*;
     use crate::karamellib::compiler::value::KaramelPrimative;
-    use crate::karamellib::compiler::ast::KaramelAstType;
+    use crate::karamellib::compiler::ast::{KaramelAstPosition, KaramelAstType};
     use std::rc::Rc;
 
     #[warn(unused_macros)]
@@ -31,17 +31,45 @@ mod tests {
 baris=2048"#, Ok(Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
     operator: KaramelOperatorType::Assign,
-    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0))))
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))),
+    position: KaramelAstPosition::default()
 }),
 Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("baris".to_string())),
     operator: KaramelOperatorType::Assign,
-    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2048.0))))
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2048.0)))),
+    position: KaramelAstPosition::default()
 })].to_vec()))));
 
 test_compare!(block_3, "erhan=1024", Ok(Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
     operator: KaramelOperatorType::Assign,
-    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0))))
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))),
+    position: KaramelAstPosition::default()
 })));
-}
\ No newline at end of file
+
+fn number_assignment(name: &str, number: f64) -> Rc<KaramelAstType> {
+    Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol(name.to_string())),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(number)))),
+        position: KaramelAstPosition::default()
+    })
+}
+
+test_compare!(block_4, "erhan=1024;", Ok(number_assignment("erhan", 1024.0)));
+test_compare!(block_5, "erhan=1024; baris=2048", Ok(Rc::new(KaramelAstType::Block([number_assignment("erhan", 1024.0), number_assignment("baris", 2048.0)].to_vec()))));
+test_compare!(block_6, r#"erhan=1024;
+baris=2048;"#, Ok(Rc::new(KaramelAstType::Block([number_assignment("erhan", 1024.0), number_assignment("baris", 2048.0)].to_vec()))));
+
+/* Repeated and leading ';' are empty statements */
+test_compare!(block_7, "erhan=1024;; baris=2048;;", Ok(Rc::new(KaramelAstType::Block([number_assignment("erhan", 1024.0), number_assignment("baris", 2048.0)].to_vec()))));
+test_compare!(block_8, r#"; erhan=1024
+;
+baris=2048"#, Ok(Rc::new(KaramelAstType::Block([number_assignment("erhan", 1024.0), number_assignment("baris", 2048.0)].to_vec()))));
+
+/* Programs without any statement */
+test_compare!(block_9, "", Ok(Rc::new(KaramelAstType::None)));
+test_compare!(block_10, "  \n\n    \n", Ok(Rc::new(KaramelAstType::None)));
+test_compare!(block_11, "// yorum\n   /* diğer yorum */\n", Ok(Rc::new(KaramelAstType::None)));
+}
@@ -49,4 +49,23 @@ mod tests {
     memory_check!(memory_5, "'erhan' + 'barış'", vec![KaramelPrimative::Text(Rc::new("erhan".to_string())), KaramelPrimative::Text(Rc::new("barış".to_string()))]);
     memory_check!(memory_6, "'erhan' + '-' + 'barış'", vec![KaramelPrimative::Text(Rc::new("erhan".to_string())), KaramelPrimative::Text(Rc::new("-".to_string())), KaramelPrimative::Text(Rc::new("barış".to_string()))]);
     memory_check!(memory_7, "doğru == yanlış", vec![KaramelPrimative::Bool(true), KaramelPrimative::Bool(false)]);
-}
\ No newline at end of file
+
+    #[test]
+    fn storage_dump_1() {
+        let mut storage = StaticStorage::new(0);
+        storage.add_variable("toplam");
+        storage.add_variable("sayaç");
+        storage.add_constant(Rc::new(KaramelPrimative::Number(1024.0)));
+        storage.add_constant(Rc::new(KaramelPrimative::Text(Rc::new("karamel".to_string()))));
+
+        let mut buffer = String::new();
+        storage.dump_to(&mut buffer).unwrap();
+
+        assert!(buffer.contains("VARIABLE DUMP"));
+        assert!(buffer.contains("CONSTANT DUMP"));
+        assert!(buffer.contains("║   0 ║ toplam"), "{}", buffer);
+        assert!(buffer.contains("║   1 ║ sayaç"), "{}", buffer);
+        assert!(buffer.contains("1024"), "{}", buffer);
+        assert!(buffer.contains("karamel"), "{}", buffer);
+    }
+}
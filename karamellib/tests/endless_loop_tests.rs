@@ -28,19 +28,22 @@ mod tests {
 "#, Ok(Rc::new(KaramelAstType::EndlessLoop(Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
     operator: KaramelOperatorType::Assign,
-    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+    position: KaramelAstPosition::default()
 })))));*/
 /*test_compare!(endless_2, r#"sonsuz:
     erhan=123   
     print(1)"#, Ok(Rc::new(KaramelAstType::EndlessLoop(Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
     operator: KaramelOperatorType::Assign,
-    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+    position: KaramelAstPosition::default()
 }),
 Rc::new(KaramelAstType::FuncCall {
     func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
     arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec(),
-    assign_to_temp: Cell::new(false)
+    assign_to_temp: Cell::new(false),
+    position: KaramelAstPosition::default()
 })
 ].to_vec()))))));
 test_compare!(endless_3, r#"sonsuz
@@ -48,7 +51,9 @@ test_compare!(endless_3, r#"sonsuz
     print(1)"#, Err(KaramelError {
         error_type: KaramelErrorType::ColonMarkMissing,
         line: 0,
-        column: 6
+        column: 6,
+        span: None,
+        backtrace: Vec::new()
     }));*/
 /*test_compare!(endless_4, r#"sonsuz:
     erhan=123   
@@ -56,23 +61,29 @@ test_compare!(endless_3, r#"sonsuz
     kır"#, Ok(Rc::new(KaramelAstType::EndlessLoop(Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
     operator: KaramelOperatorType::Assign,
-    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+    position: KaramelAstPosition::default()
 }),
 Rc::new(KaramelAstType::FuncCall {
     func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
     arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec(),
-    assign_to_temp: Cell::new(false)
+    assign_to_temp: Cell::new(false),
+    position: KaramelAstPosition::default()
 }),
 Rc::new(KaramelAstType::Break)
 ].to_vec()))))));*/
 test_compare!(endless_5, r#"kır"#, Err(KaramelError {
     error_type: KaramelErrorType::BreakAndContinueBelongToLoops,
     column: 3,
-    line: 0
+    line: 0,
+    span: None,
+    backtrace: Vec::new()
 }));
 test_compare!(endless_6, r#"devam"#, Err(KaramelError {
     error_type: KaramelErrorType::BreakAndContinueBelongToLoops,
     column: 5,
-    line: 0
+    line: 0,
+    span: None,
+    backtrace: Vec::new()
 }));
 }
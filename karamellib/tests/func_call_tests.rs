@@ -7,7 +7,7 @@ mod tests {
     use crate::karamellib::parser::*;
     use crate::karamellib::syntax::*;
     use crate::karamellib::compiler::value::KaramelPrimative;
-    use crate::karamellib::compiler::ast::KaramelAstType;
+    use crate::karamellib::compiler::ast::{KaramelAstPosition, KaramelAstType};
     use std::cell::Cell;
     use std::rc::Rc;
 
@@ -31,31 +31,36 @@ mod tests {
     test_compare!(func_call_1, "print()", Ok(Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
         arguments: Vec::new(),
-        assign_to_temp: Cell::new(false)
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
     })));
 
     test_compare!(func_call_2, "print(1)", Ok(Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
         arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec(),
-        assign_to_temp: Cell::new(false)
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
     })));
 
     test_compare!(func_call_3, "print( 1 )", Ok(Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
         arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec(),
-        assign_to_temp: Cell::new(false)
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
     })));
 
     test_compare!(func_call_4, "print( 1 , 2 )", Ok(Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
         arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))].to_vec(),
-        assign_to_temp: Cell::new(false)
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
     })));
 
     test_compare!(func_call_5, "print(1,2)", Ok(Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
         arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))].to_vec(),
-        assign_to_temp: Cell::new(false)
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
     })));
 
     test_compare!(func_call_6, "print(1,2,'erhan')", Ok(Rc::new(KaramelAstType::FuncCall {
@@ -63,18 +68,23 @@ mod tests {
         arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
                     Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
                     Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("erhan".to_string())))))].to_vec(),
-                    assign_to_temp: Cell::new(false)
+                    assign_to_temp: Cell::new(false),
+                    position: KaramelAstPosition::default()
     })));
 
     test_compare!(func_call_7, "print(,2,'erhan')", Err(KaramelError {
         error_type: KaramelErrorType::SyntaxError,
         column: 6,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(func_call_8, "print(", Err(KaramelError {
         error_type: KaramelErrorType::RightParanthesesMissing,
         column: 6,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(func_call_9, "data=print()", Ok(Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("data".to_string())),
@@ -82,34 +92,73 @@ mod tests {
         expression: Rc::new(KaramelAstType::FuncCall {
             func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
             arguments: Vec::new(),
-            assign_to_temp: Cell::new(true)
-        })
+            assign_to_temp: Cell::new(true),
+            position: KaramelAstPosition::default()
+        }),
+        position: KaramelAstPosition::default()
     })));
     test_compare!(func_call_10, "data1() + data2()", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::FuncCall {
             func_name_expression: Rc::new(KaramelAstType::Symbol("data1".to_string())),
             arguments: Vec::new(),
-            assign_to_temp: Cell::new(true)
+            assign_to_temp: Cell::new(true),
+            position: KaramelAstPosition::default()
         }),
         operator: karamellib::types::KaramelOperatorType::Addition,
         right: Rc::new(KaramelAstType::FuncCall {
             func_name_expression: Rc::new(KaramelAstType::Symbol("data2".to_string())),
             arguments: Vec::new(),
-            assign_to_temp: Cell::new(true)
+            assign_to_temp: Cell::new(true),
+            position: KaramelAstPosition::default()
         })
     })));
     test_compare!(func_call_11, "data1() > data2()", Ok(Rc::new(KaramelAstType::Control {
         left: Rc::new(KaramelAstType::FuncCall {
             func_name_expression: Rc::new(KaramelAstType::Symbol("data1".to_string())),
             arguments: Vec::new(),
-            assign_to_temp: Cell::new(true)
+            assign_to_temp: Cell::new(true),
+            position: KaramelAstPosition::default()
         }),
         operator: karamellib::types::KaramelOperatorType::GreaterThan,
         right: Rc::new(KaramelAstType::FuncCall {
             func_name_expression: Rc::new(KaramelAstType::Symbol("data2".to_string())),
             arguments: Vec::new(),
-            assign_to_temp: Cell::new(true)
+            assign_to_temp: Cell::new(true),
+            position: KaramelAstPosition::default()
         })
     })));
     test_compare!(func_call_12, "gç::satıryaz", Ok(Rc::new(KaramelAstType::ModulePath(["gç".to_string(), "satıryaz".to_string()].to_vec()))));
-}
\ No newline at end of file
+    test_compare!(func_call_13, "print(1, 2,)", Ok(Rc::new(KaramelAstType::FuncCall {
+        func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
+        arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))].to_vec(),
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
+    })));
+    test_compare!(func_call_14, "print(,)", Err(KaramelError {
+        error_type: KaramelErrorType::SyntaxError,
+        column: 6,
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_compare!(func_call_15, "print(1,,2)", Err(KaramelError {
+        error_type: KaramelErrorType::SyntaxError,
+        column: 6,
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_compare!(func_call_16, "topla(1, *liste)", Ok(Rc::new(KaramelAstType::FuncCall {
+        func_name_expression: Rc::new(KaramelAstType::Symbol("topla".to_string())),
+        arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), Rc::new(KaramelAstType::Spread(Rc::new(KaramelAstType::Symbol("liste".to_string()))))].to_vec(),
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
+    })));
+    test_compare!(func_call_17, "topla(*)", Err(KaramelError {
+        error_type: KaramelErrorType::SyntaxError,
+        column: 8,
+        line: 0,
+        span: Some(7..8),
+        backtrace: Vec::new()
+    }));
+}
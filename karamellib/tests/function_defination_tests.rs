@@ -8,7 +8,7 @@ mod tests {
     use crate::karamellib::types::*;
     use crate::karamellib::syntax::*;
     use crate::karamellib::compiler::value::KaramelPrimative;
-    use crate::karamellib::compiler::ast::KaramelAstType;
+    use crate::karamellib::compiler::ast::{KaramelAstPosition, KaramelAstType};
     use std::rc::Rc;
 
     #[warn(unused_macros)]
@@ -33,48 +33,60 @@ fonk test():
     erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
         name: "test".to_string(),
         arguments: Vec::new(),
+        argument_positions: Vec::new(),
         body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
             operator: KaramelOperatorType::Assign,
-            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+            position: KaramelAstPosition::default()
         }),
-        Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec()))
+        Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec())),
+        position: KaramelAstPosition::default()
     })));
     test_compare!(func_def_2, r#"
 fonk test(a):
     erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
         name: "test".to_string(),
         arguments: ["a".to_string()].to_vec(),
+        argument_positions: vec![KaramelAstPosition::default(); 1],
         body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
             operator: KaramelOperatorType::Assign,
-            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+            position: KaramelAstPosition::default()
         }),
-        Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec()))
+        Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec())),
+        position: KaramelAstPosition::default()
     })));
     test_compare!(func_def_3, r#"
 fonk test(a, b    ,   c):
     erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
         name: "test".to_string(),
         arguments: ["a".to_string(), "b".to_string(), "c".to_string()].to_vec(),
+        argument_positions: vec![KaramelAstPosition::default(); 3],
         body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
             operator: KaramelOperatorType::Assign,
-            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+            position: KaramelAstPosition::default()
         }),
-        Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec()))
+        Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec())),
+        position: KaramelAstPosition::default()
     })));
     test_compare!(func_def_4, r#"
 fonk test:
     erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
             name: "test".to_string(),
             arguments: Vec::new(),
+            argument_positions: Vec::new(),
             body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
                 variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
                 operator: KaramelOperatorType::Assign,
-                expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+                expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+                position: KaramelAstPosition::default()
             }),
-            Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec()))
+            Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec())),
+            position: KaramelAstPosition::default()
         })));
         test_compare!(func_def_6, r#"
 fonk test   :
@@ -84,40 +96,77 @@ fonk test   :
         erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
                 name: "test".to_string(),
                 arguments: Vec::new(),
+                argument_positions: Vec::new(),
                 body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
                     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
                     operator: KaramelOperatorType::Assign,
-                    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+                    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+                    position: KaramelAstPosition::default()
                 }),
-                Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec()))
+                Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec())),
+                position: KaramelAstPosition::default()
             })));
             test_compare!(func_def_7, r#"
 fonk test
     erhan=123"#, Err(KaramelError {
         error_type: KaramelErrorType::ColonMarkMissing,
         column: 9,
-        line: 1
+        line: 1,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_compare!(func_def_7_1, r#"
+fonk çözüm
+    erhan=123"#, Err(KaramelError {
+        error_type: KaramelErrorType::ColonMarkMissing,
+        column: 10,
+        line: 1,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_compare!(func_def_7_2, r#"
+fonk çöz(ağaç)
+    erhan=123"#, Err(KaramelError {
+        error_type: KaramelErrorType::ColonMarkMissing,
+        column: 14,
+        line: 1,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_compare!(func_def_7_3, r#"
+fonk çöz erhan=123"#, Err(KaramelError {
+        error_type: KaramelErrorType::ColonMarkMissing,
+        column: 14,
+        line: 1,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(func_def_8, r#"
 fonk test(:
     erhan=123"#, Err(KaramelError {
         error_type: KaramelErrorType::ArgumentMustBeText,
         column: 11,
-        line: 1
+        line: 1,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(func_def_9, r#"
 fonk test(a:
     erhan=123"#, Err(KaramelError {
         error_type: KaramelErrorType::RightParanthesesMissing,
         column: 12,
-        line: 1
+        line: 1,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(func_def_10, r#"
 fonk test(a):
 "#, Err(KaramelError {
     error_type: KaramelErrorType::FunctionConditionBodyNotFound,
     column: 13,
-    line: 1
+    line: 1,
+    span: None,
+    backtrace: Vec::new()
 }));
 test_compare!(func_def_11, r#"
 fonk (a):
@@ -125,7 +174,9 @@ fonk (a):
 "#, Err(KaramelError {
     error_type: KaramelErrorType::FunctionNameNotDefined,
     column: 6,
-    line: 1
+    line: 1,
+    span: None,
+    backtrace: Vec::new()
 }));
 test_compare!(func_def_12, r#"
 fonk :
@@ -133,7 +184,9 @@ fonk :
 "#, Err(KaramelError {
     error_type: KaramelErrorType::FunctionNameNotDefined,
     column: 6,
-    line: 1
+    line: 1,
+    span: None,
+    backtrace: Vec::new()
 }));
 test_compare!(func_def_13, r#"
 fonk test(1):
@@ -141,7 +194,9 @@ fonk test(1):
 "#, Err(KaramelError {
     error_type: KaramelErrorType::ArgumentMustBeText,
     column: 11,
-    line: 1
+    line: 1,
+    span: None,
+    backtrace: Vec::new()
 }));
 test_compare!(func_def_14, r#"
 test=1
@@ -149,7 +204,9 @@ döndür test
 "#, Err(KaramelError {
     error_type: KaramelErrorType::ReturnMustBeUsedInFunction,
     column: 6,
-    line: 2
+    line: 2,
+    span: None,
+    backtrace: Vec::new()
 }));
 test_compare!(func_def_15, r#"
 fonk test():
@@ -157,12 +214,15 @@ fonk test():
     döndür erhan"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
     name: "test".to_string(),
     arguments: Vec::new(),
+    argument_positions: Vec::new(),
     body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
     }),
-    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Symbol("erhan".to_string()))))].to_vec()))
+    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Symbol("erhan".to_string()))))].to_vec())),
+    position: KaramelAstPosition::default()
 })));
 test_compare!(func_def_16, r#"
 fonk test():
@@ -170,11 +230,118 @@ fonk test():
     döndür"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
     name: "test".to_string(),
     arguments: Vec::new(),
+    argument_positions: Vec::new(),
+    body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
+    }),
+    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec())),
+    position: KaramelAstPosition::default()
+})));
+test_compare!(func_def_17, r#"
+fonk test(): erhan=123; döndür erhan"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
+    name: "test".to_string(),
+    arguments: Vec::new(),
+    argument_positions: Vec::new(),
+    body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
+    }),
+    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Symbol("erhan".to_string()))))].to_vec())),
+    position: KaramelAstPosition::default()
+})));
+test_compare!(func_def_18, r#"
+fonk test(): erhan=123;
+barış=1"#, Ok(Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::FunctionDefination {
+    name: "test".to_string(),
+    arguments: Vec::new(),
+    argument_positions: Vec::new(),
     body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
     }),
-    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec()))
+    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec())),
+    position: KaramelAstPosition::default()
+}),
+Rc::new(KaramelAstType::Assignment {
+    variable: Rc::new(KaramelAstType::Symbol("barış".to_string())),
+    operator: KaramelOperatorType::Assign,
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+    position: KaramelAstPosition::default()
+})].to_vec()))));
+test_compare!(func_def_19, r#"
+fonk test(): döndür"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
+    name: "test".to_string(),
+    arguments: Vec::new(),
+    argument_positions: Vec::new(),
+    body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None))),
+    position: KaramelAstPosition::default()
+})));
+test_compare!(func_def_20, r#"
+fonk test(): erhan=123; döndür"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
+    name: "test".to_string(),
+    arguments: Vec::new(),
+    argument_positions: Vec::new(),
+    body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
+    }),
+    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec())),
+    position: KaramelAstPosition::default()
+})));
+test_compare!(func_def_21, r#"
+fonk test(a, b,): döndür"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
+    name: "test".to_string(),
+    arguments: ["a".to_string(), "b".to_string()].to_vec(),
+    argument_positions: vec![KaramelAstPosition::default(); 2],
+    body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None))),
+    position: KaramelAstPosition::default()
+})));
+test_compare!(func_def_22, r#"
+fonk test(,): döndür"#, Err(KaramelError {
+    error_type: KaramelErrorType::ArgumentMustBeText,
+    column: 11,
+    line: 1,
+    span: None,
+    backtrace: Vec::new()
+}));
+test_compare!(func_def_23, r#"
+fonk test(a,,b): döndür"#, Err(KaramelError {
+    error_type: KaramelErrorType::ArgumentMustBeText,
+    column: 13,
+    line: 1,
+    span: None,
+    backtrace: Vec::new()
+}));
+test_compare!(func_def_24, r#"
+fn test(a): döndür"#, Err(KaramelError {
+    error_type: KaramelErrorType::FunctionKeywordNotValid("fn".to_string()),
+    column: 2,
+    line: 1,
+    span: None,
+    backtrace: Vec::new()
+}));
+test_compare!(func_def_25, r#"
+fonksiyon test:
+    döndür"#, Err(KaramelError {
+    error_type: KaramelErrorType::FunctionKeywordNotValid("fonksiyon".to_string()),
+    column: 9,
+    line: 1,
+    span: None,
+    backtrace: Vec::new()
+}));
+test_compare!(func_def_26, r#"fn = 10"#, Ok(Rc::new(KaramelAstType::Assignment {
+    variable: Rc::new(KaramelAstType::Symbol("fn".to_string())),
+    operator: KaramelOperatorType::Assign,
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+    position: KaramelAstPosition::default()
 })));
-}
\ No newline at end of file
+}
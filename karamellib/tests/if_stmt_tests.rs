@@ -8,7 +8,7 @@ mod tests {
     use crate::karamellib::parser::*;
     use crate::karamellib::syntax::*;
     use crate::karamellib::compiler::value::KaramelPrimative;
-    use crate::karamellib::compiler::ast::{KaramelAstType, KaramelIfStatementElseItem};
+    use crate::karamellib::compiler::ast::{KaramelAstPosition, KaramelAstType, KaramelIfStatementElseItem};
     use std::cell::Cell;
     use std::rc::Rc;
 
@@ -42,12 +42,14 @@ veya:
     body: Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
     }),
     else_body: Some(Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234.0)))),
+        position: KaramelAstPosition::default()
     })),
     else_if: Vec::new()
     })));
@@ -62,7 +64,8 @@ veya:
     body: Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
     }),
     else_body:None,
     else_if: Vec::new()
@@ -79,12 +82,14 @@ veya:
     body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
     }),
     Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
         arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec(),
-        assign_to_temp: Cell::new(false)
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
     })
     ].to_vec())),
     else_body:None,
@@ -105,23 +110,27 @@ veya :
     body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
     }),
     Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
         arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec(),
-        assign_to_temp: Cell::new(false)
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
     })
     ].to_vec())),
     else_body: Some(Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(321.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(321.0)))),
+        position: KaramelAstPosition::default()
     }),
     Rc::new(KaramelAstType::FuncCall {
         func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
         arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))].to_vec(),
-        assign_to_temp: Cell::new(false)
+        assign_to_temp: Cell::new(false),
+        position: KaramelAstPosition::default()
     })
     ].to_vec()))),
     else_if: Vec::new()
@@ -142,12 +151,14 @@ veya:
     body: Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
     }),
     else_body: Some(Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234.0)))),
+        position: KaramelAstPosition::default()
     })),
     else_if: [Rc::new(KaramelIfStatementElseItem::new(Rc::new(KaramelAstType::Control {
         left: Rc::new(KaramelAstType::Binary {
@@ -160,7 +171,8 @@ veya:
     }), Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(12345.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(12345.0)))),
+        position: KaramelAstPosition::default()
     })))].to_vec()
     })));
 
@@ -181,12 +193,14 @@ veya:
     body: Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        position: KaramelAstPosition::default()
     }),
     else_body: Some(Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234.0)))),
+        position: KaramelAstPosition::default()
     })),
     else_if: [Rc::new(KaramelIfStatementElseItem::new(Rc::new(KaramelAstType::Control {
         left: Rc::new(KaramelAstType::Binary {
@@ -199,7 +213,8 @@ veya:
     }), Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(12345.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(12345.0)))),
+        position: KaramelAstPosition::default()
     }))),
     Rc::new(KaramelIfStatementElseItem::new(Rc::new(KaramelAstType::Control {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10000000.0)))),
@@ -212,7 +227,8 @@ veya:
     }), Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
-        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123456.0))))
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123456.0)))),
+        position: KaramelAstPosition::default()
     })))].to_vec()
     })));
 
@@ -229,7 +245,9 @@ veya:
 "#, Err(KaramelError {
     error_type: KaramelErrorType::ElseIsUsed,
     column: 5,
-    line: 8
+    line: 8,
+    span: None,
+    backtrace: Vec::new()
 }));
 
 test_compare!(if_8, r#"1024 * 123 ise:
@@ -243,7 +261,9 @@ veya 1024 * 123 < 10_000_000 ise:
 "#, Err(KaramelError {
     error_type: KaramelErrorType::ElseIsUsed,
     column: 28,
-    line: 4
+    line: 4,
+    span: None,
+    backtrace: Vec::new()
 }));
 
 
@@ -261,7 +281,8 @@ condition: Rc::new(KaramelAstType::Binary {
 body: Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
     operator: KaramelOperatorType::Assign,
-    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+    position: KaramelAstPosition::default()
 }),
 else_body: None,
 else_if: [Rc::new(KaramelIfStatementElseItem::new(Rc::new(KaramelAstType::Control {
@@ -275,7 +296,8 @@ else_if: [Rc::new(KaramelIfStatementElseItem::new(Rc::new(KaramelAstType::Contro
 }), Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
     operator: KaramelOperatorType::Assign,
-    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(12345.0))))
+    expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(12345.0)))),
+    position: KaramelAstPosition::default()
 })))].to_vec()
 })));
 }
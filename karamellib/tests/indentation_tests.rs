@@ -99,4 +99,7 @@ test_success!(indentation_12, r#"
 veya:
     erhan=1234
 erhan=22"#);
-}
\ No newline at end of file
+    test_success!(indentation_tab_1, "1024 * 123 ise:\n\terhan=123\n\t1024 ise:\n\t\terhan=1\nveya:\n\terhan=1234");
+    test_fail!(indentation_tab_2, "1024 * 123 ise:\n\terhan=123\n\t\terhan=1");
+    test_success!(indentation_crlf_1, "1024 * 123 ise:\r\n    erhan=123\r\n\r\nveya:\r\n    erhan=1234\r\n");
+}
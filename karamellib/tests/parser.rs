@@ -4,6 +4,8 @@ extern crate karamellib;
 mod tests {
     use crate::karamellib::parser::*;
     use crate::karamellib::types::*;
+    use crate::karamellib::syntax::SyntaxParser;
+    use crate::karamellib::error::KaramelErrorType;
 
     #[warn(unused_macros)]
     macro_rules! test_number {
@@ -20,7 +22,7 @@ mod tests {
 
                 assert_eq!(1, tokens.len());
                 match &tokens[0].token_type {
-                    KaramelTokenType::$type(num) => assert_eq!(*num, $result),
+                    KaramelTokenType::$type(num, ..) => assert_eq!(*num, $result),
                     _ => assert_eq!(true, false)
                 }
             }
@@ -177,6 +179,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn symbol_case() {
+        let mut parser = Parser::new("Sayı sayı İsim isim IĞDIR ığdır Doğru");
+        match parser.parse() {
+            Err(_) => assert_eq!(true, false),
+            _ => ()
+        };
+        let symbols = parser.tokens().iter().filter_map(|token| match &token.token_type {
+            KaramelTokenType::Symbol(symbol) => Some(symbol.to_string()),
+            KaramelTokenType::WhiteSpace(..) => None,
+            _ => Some("".to_string())
+        }).collect::<Vec<_>>();
+
+        assert_eq!(symbols, ["Sayı", "sayı", "İsim", "isim", "IĞDIR", "ığdır", "Doğru"]);
+    }
+
     #[test]
     fn new_line_1() {
         let mut parser = Parser::new("\n");
@@ -188,7 +206,7 @@ mod tests {
 
         assert_eq!(1, tokens.len());
         match &tokens[0].token_type {
-            KaramelTokenType::NewLine(count) => assert_eq!(*count == 0, true),
+            KaramelTokenType::NewLine(count, _) => assert_eq!(*count == 0, true),
             _ => assert_eq!(true, false)
         }
     }
@@ -204,17 +222,38 @@ mod tests {
 
         assert_eq!(3, tokens.len());
         match &tokens[0].token_type {
-            KaramelTokenType::NewLine(count) => assert_eq!(*count == 5, true),
+            KaramelTokenType::NewLine(count, _) => assert_eq!(*count == 5, true),
             _ => assert_eq!(true, false)
         }
 
         match &tokens[1].token_type {
-            KaramelTokenType::NewLine(count) => assert_eq!(*count == 4, true),
+            KaramelTokenType::NewLine(count, _) => assert_eq!(*count == 4, true),
             _ => assert_eq!(true, false)
         }
 
         match &tokens[2].token_type {
-            KaramelTokenType::NewLine(count) => assert_eq!(*count == 3, true),
+            KaramelTokenType::NewLine(count, _) => assert_eq!(*count == 3, true),
+            _ => assert_eq!(true, false)
+        }
+    }
+
+    #[test]
+    fn new_line_3() {
+        let mut parser = Parser::new("(\n  )\n");
+        match parser.parse() {
+            Err(_) => assert_eq!(true, false),
+            _ => ()
+        };
+        let tokens = parser.tokens();
+
+        assert_eq!(4, tokens.len());
+        match &tokens[1].token_type {
+            KaramelTokenType::WhiteSpace(count, _) => assert_eq!(*count == 2, true),
+            _ => assert_eq!(true, false)
+        }
+
+        match &tokens[3].token_type {
+            KaramelTokenType::NewLine(count, _) => assert_eq!(*count == 0, true),
             _ => assert_eq!(true, false)
         }
     }
@@ -230,12 +269,151 @@ mod tests {
 
         assert_eq!(1, tokens.len());
         match &tokens[0].token_type {
-            KaramelTokenType::WhiteSpace(count) => assert_eq!(*count == 5, true),
+            KaramelTokenType::WhiteSpace(count, _) => assert_eq!(*count == 5, true),
             _ => assert_eq!(true, false)
         }
     }
 
 
+    #[test]
+    fn whitespace_2() {
+        let mut parser = Parser::new("a   b");
+        parser.parse().unwrap();
+        let tokens = parser.tokens();
+
+        assert_eq!(3, tokens.len());
+        match &tokens[1].token_type {
+            KaramelTokenType::WhiteSpace(count, _) => assert_eq!(*count, 3),
+            _ => assert_eq!(true, false)
+        }
+    }
+
+    #[test]
+    fn whitespace_3() {
+        let code = " ".repeat(300);
+        let mut parser = Parser::new(&code);
+        parser.parse().unwrap();
+        let tokens = parser.tokens();
+
+        assert_eq!(1, tokens.len());
+        match &tokens[0].token_type {
+            KaramelTokenType::WhiteSpace(count, _) => assert_eq!(*count, u8::MAX),
+            _ => assert_eq!(true, false)
+        }
+    }
+
+    #[test]
+    fn new_line_4() {
+        let code = format!("\n\n{}a", " ".repeat(300));
+        let mut parser = Parser::new(&code);
+        parser.parse().unwrap();
+        let tokens = parser.tokens();
+
+        assert_eq!(3, tokens.len());
+        match (&tokens[0].token_type, &tokens[1].token_type) {
+            (KaramelTokenType::NewLine(first, _), KaramelTokenType::NewLine(second, _)) => assert_eq!((*first, *second), (0, u8::MAX)),
+            _ => assert_eq!(true, false)
+        }
+    }
+
+
+    #[test]
+    fn indentation_type_1() {
+        let mut parser = Parser::new("a\n\tb\n    c");
+        parser.parse().unwrap_err();
+
+        let mut tab_parser = Parser::new("a\n\tb");
+        tab_parser.parse().unwrap();
+        assert_eq!(tab_parser.tokens()[1].token_type, KaramelTokenType::NewLine(1, KaramelWhitespaceType::Tab));
+
+        let mut space_parser = Parser::new("a\n    b");
+        space_parser.parse().unwrap();
+        assert_eq!(space_parser.tokens()[1].token_type, KaramelTokenType::NewLine(4, KaramelWhitespaceType::Space));
+    }
+
+    #[test]
+    fn whitespace_4() {
+        let mut parser = Parser::new("a\t\t  b");
+        parser.parse().unwrap();
+        let tokens = parser.tokens();
+
+        assert_eq!(4, tokens.len());
+        assert_eq!(tokens[1].token_type, KaramelTokenType::WhiteSpace(2, KaramelWhitespaceType::Tab));
+        assert_eq!(tokens[2].token_type, KaramelTokenType::WhiteSpace(2, KaramelWhitespaceType::Space));
+    }
+
+    #[warn(unused_macros)]
+    macro_rules! test_line_break {
+        ($name:ident, $text:expr, $new_lines:expr, $last_line:expr) => {
+            #[test]
+            fn $name () {
+                let mut parser = Parser::new($text);
+                parser.parse().unwrap();
+                let tokens = parser.tokens();
+
+                assert_eq!(tokens.iter().filter(|token| matches!(token.token_type, KaramelTokenType::NewLine(..))).count(), $new_lines);
+                assert_eq!(tokens.last().unwrap().token_type, KaramelTokenType::Symbol(std::rc::Rc::new("b".to_string())));
+                assert_eq!(tokens.last().unwrap().line, $last_line);
+            }
+        };
+    }
+
+    test_line_break!(line_break_1, "a\r\nb", 1, 1);
+    test_line_break!(line_break_2, "a\nb", 1, 1);
+    test_line_break!(line_break_3, "a\rb", 1, 1);
+    test_line_break!(line_break_4, "a\r\n\r\n  b", 2, 2);
+    test_line_break!(line_break_5, "a\n\rb", 2, 2);
+    test_line_break!(line_break_6, "a // yorum\r\nb", 1, 1);
+    test_line_break!(line_break_7, "a /*\r\n\r\n*/ b", 0, 2);
+
+    /* '\r' is handled by the line parser, operator parser never sees it */
+    #[test]
+    fn carriage_return_1() {
+        let mut parser = Parser::new("(1,\r2)\r\nb");
+        parser.parse().unwrap();
+        let tokens = parser.tokens();
+
+        assert_eq!(tokens.len(), 8);
+        assert_eq!(tokens[3].token_type, KaramelTokenType::WhiteSpace(0, KaramelWhitespaceType::Space));
+        assert_eq!(tokens[4].token_type, KaramelTokenType::Integer(2, KaramelNumberSystem::Decimal));
+        assert_eq!(tokens[4].span, 4..5);
+        assert_eq!(tokens[6].span, 6..8);
+        assert_eq!(tokens[7].span, 8..9);
+    }
+
+    #[test]
+    fn whitespace_5() {
+        let mut parser = Parser::new("a\u{00A0}=\u{00A0}\u{00A0}1");
+        parser.parse().unwrap();
+        let tokens = parser.tokens();
+
+        assert_eq!(5, tokens.len());
+        assert_eq!(tokens[1].token_type, KaramelTokenType::WhiteSpace(1, KaramelWhitespaceType::Space));
+        assert_eq!(tokens[2].token_type, KaramelTokenType::Operator(KaramelOperatorType::Assign));
+        assert_eq!(tokens[3].token_type, KaramelTokenType::WhiteSpace(2, KaramelWhitespaceType::Space));
+    }
+
+    #[test]
+    fn mixed_indentation_1() {
+        let mut parser = Parser::new("a ise:\n\t b");
+        assert_eq!(parser.parse().unwrap_err().error_type, KaramelErrorType::MixedIndentation);
+    }
+
+    #[test]
+    fn mixed_indentation_2() {
+        let mut parser = Parser::new("a ise:\n    b\nc ise:\n\td");
+        let error = parser.parse().unwrap_err();
+        assert_eq!((error.error_type, error.line), (KaramelErrorType::MixedIndentation, 3));
+    }
+
+    /* Empty lines and lines inside of the brackets are not indentation */
+    #[test]
+    fn mixed_indentation_3() {
+        let mut parser = Parser::new("a ise:\n    b\n\t\nc = [1,\n\t2]");
+        assert!(parser.parse().is_ok());
+    }
+
+
     parse_failed!(text_1, "'merhaba dünya");
     parse_failed!(text_2, "\"merhaba dünya");
 
@@ -246,8 +424,63 @@ mod tests {
     test_comment!(comment_5, "/* // */");
     parse_failed!(comment_6, "/*");
 
+    /* Parsers outside of the library use the same trait with the buildin parsers */
+    struct HashParser;
+
+    impl TokenParser for HashParser {
+        fn check(&self, tokinizer: &mut Tokinizer) -> bool {
+            tokinizer.get_char() == '#'
+        }
+
+        fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType> {
+            let start_column = tokinizer.column;
+            tokinizer.increase_index();
+            tokinizer.add_token(start_column, KaramelTokenType::Operator(KaramelOperatorType::Not));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn token_parser_1() {
+        let data = "#";
+        let mut tokinizer = Tokinizer {
+            column: 0,
+            line: 0,
+            tokens: Vec::new(),
+            iter: data.chars().peekable(),
+            iter_second: data.chars().peekable(),
+            iter_third: data.chars().peekable(),
+            data: data.to_string(),
+            index: 0,
+            token_index: 0,
+            bracket_depth: 0,
+            indentation_type: None
+        };
+
+        let parsers: Vec<Box<dyn TokenParser>> = vec![Box::new(HashParser)];
+        assert!(parsers[0].check(&mut tokinizer));
+        assert!(parsers[0].parse(&mut tokinizer).is_ok());
+        assert_eq!(tokinizer.tokens.len(), 1);
+        assert_eq!((tokinizer.tokens[0].start, tokinizer.tokens[0].end, tokinizer.tokens[0].span.clone()), (0, 1, 0..1));
+    }
+
     parse_failed!(operator_1, "#");
 
+    #[test]
+    fn invalid_char_1() {
+        let mut parser = Parser::new("erhan = 1 # 2");
+        let error = parser.parse().unwrap_err();
+        assert_eq!((error.error_type, error.line, error.column), (KaramelErrorType::CharNotValid, 0, 10));
+        assert_eq!(error.span, Some(10..11));
+    }
+
+    #[test]
+    fn invalid_char_2() {
+        let mut parser = Parser::new("a = 1\nb = #");
+        let error = parser.parse().unwrap_err();
+        assert_eq!((error.error_type, error.line, error.column), (KaramelErrorType::CharNotValid, 1, 4));
+    }
+
     test_number!(integer_1, Integer, "1024", 1024);
     test_number!(integer_2, Integer, "1024000", 1024000);
     test_number!(integer_3, Integer, "123", 123);
@@ -271,6 +504,26 @@ mod tests {
     test_number!(binary_4, Integer, "0B00000000011111111111111111111111", 8388607);
 
 
+    #[warn(unused_macros)]
+    macro_rules! test_number_system {
+        ($name:ident, $text:expr, $result:expr) => {
+            #[test]
+            fn $name () {
+                let mut parser = Parser::new($text);
+                parser.parse().unwrap();
+                match &parser.tokens()[0].token_type {
+                    KaramelTokenType::Integer(_, number_system) => assert_eq!(*number_system, $result),
+                    _ => assert_eq!(true, false)
+                }
+            }
+        };
+    }
+
+    test_number_system!(number_system_1, "0x1F", KaramelNumberSystem::Hexadecimal);
+    test_number_system!(number_system_2, "0b101", KaramelNumberSystem::Binary);
+    test_number_system!(number_system_3, "062", KaramelNumberSystem::Octal);
+    test_number_system!(number_system_4, "1024", KaramelNumberSystem::Decimal);
+
     test_number!(double_1, Double, "1024.0", 1024.0);
     #[test]
     fn double_2() {
@@ -292,7 +545,187 @@ mod tests {
     test_number!(double_5, Double, "1_23.4e+4", 1234000.0);
     test_number!(double_6, Double, "1_23.4_e+4_", 1234000.0);
     test_number!(double_7, Double, "09__9_999.9_", 99999.9);
+    test_number!(double_8, Double, "18446744073709551616.5", 18446744073709551616.5);
+
+    #[warn(unused_macros)]
+    macro_rules! number_out_of_range {
+        ($name:ident, $text:expr) => {
+            #[test]
+            fn $name () {
+                let text = $text;
+                let mut parser = Parser::new(&text);
+                let error = parser.parse().unwrap_err();
+                assert_eq!((error.error_type, error.line, error.column), (KaramelErrorType::NumberOutOfRange, 0, 0));
+                assert_eq!(error.span, Some(0..text.len()));
+            }
+        };
+    }
+
+    number_out_of_range!(number_out_of_range_1, "9223372036854775808");
+    number_out_of_range!(number_out_of_range_2, "0x8000000000000000");
+    number_out_of_range!(number_out_of_range_3, "01000000000000000000000");
+    number_out_of_range!(number_out_of_range_4, "0b1000000000000000000000000000000000000000000000000000000000000000");
+    number_out_of_range!(number_out_of_range_5, "1.0e309");
+    number_out_of_range!(number_out_of_range_6, "1".repeat(1_000_000));
+    number_out_of_range!(number_out_of_range_7, format!("{}.5", "9".repeat(1_000_000)));
+
+    #[test]
+    fn double_exponent_end() {
+        let mut parser = Parser::new("1.5e2+1");
+        parser.parse().unwrap();
+        let tokens = parser.tokens();
+
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0].token_type, KaramelTokenType::Double(150.0));
+        assert_eq!(tokens[1].token_type, KaramelTokenType::Operator(KaramelOperatorType::Addition));
+    }
+
+    #[test]
+    fn long_symbol() {
+        let symbol = "ğ".repeat(1_000_000);
+        let mut parser = Parser::new(&symbol);
+        parser.parse().unwrap();
+        assert_eq!(parser.tokens()[0].token_type, KaramelTokenType::Symbol(std::rc::Rc::new(symbol.clone())));
+        assert_eq!(parser.tokens()[0].end, 1_000_000);
+    }
 
     test_keyword!(keyword_2, "doğru", KaramelKeywordType::True);
     test_keyword!(keyword_4, "yanlış", KaramelKeywordType::False);
-}
\ No newline at end of file
+    test_keyword!(keyword_5, "döndür", KaramelKeywordType::Return);
+    test_keyword!(keyword_6, "dondur", KaramelKeywordType::Return);
+
+    #[test]
+    fn all_keywords() {
+        for (text, keyword) in KEYWORDS.iter() {
+            let mut parser = Parser::new(text);
+            assert!(parser.parse().is_ok());
+
+            let tokens = parser.tokens();
+            assert_eq!(1, tokens.len());
+            match keyword.to_operator() {
+                Some(operator) => assert_eq!(tokens[0].token_type, KaramelTokenType::Operator(operator)),
+                None => assert_eq!(tokens[0].token_type, KaramelTokenType::Keyword(*keyword))
+            };
+        }
+    }
+
+    #[test]
+    fn keyword_prefix_symbol() {
+        let mut parser = Parser::new("dönd fonkx içindeki");
+        assert!(parser.parse().is_ok());
+
+        let symbols = parser.tokens().iter().filter_map(|token| match &token.token_type {
+            KaramelTokenType::Symbol(symbol) => Some(symbol.to_string()),
+            _ => None
+        }).collect::<Vec<_>>();
+        assert_eq!(symbols, vec!["dönd", "fonkx", "içindeki"]);
+    }
+
+    #[test]
+    fn token_span() {
+        let source = "çıktı = 'şeker' // yorum\n  ğ";
+        let mut parser = Parser::new(source);
+        match parser.parse() {
+            Err(_) => assert_eq!(true, false),
+            _ => ()
+        };
+
+        let texts = parser.tokens().iter().map(|token| &source[token.span.clone()]).collect::<Vec<_>>();
+        assert_eq!(texts, ["çıktı", " ", "=", " ", "'şeker'", " ", "\n  ", "ğ"]);
+    }
+
+    #[test]
+    fn token_span_number() {
+        let source = "1_000+0x1F+1.5e3+0b10";
+        let mut parser = Parser::new(source);
+        parser.parse().unwrap();
+
+        let texts = parser.tokens().iter().map(|token| &source[token.span.clone()]).collect::<Vec<_>>();
+        assert_eq!(texts, ["1_000", "+", "0x1F", "+", "1.5e3", "+", "0b10"]);
+        assert_eq!(parser.tokens()[0].token_type, KaramelTokenType::Integer(1000, KaramelNumberSystem::Decimal));
+    }
+
+    #[test]
+    fn exponent_operator() {
+        let mut parser = Parser::new("2**8*2");
+        parser.parse().unwrap();
+        let operators = parser.tokens().iter().filter_map(|token| match token.token_type {
+            KaramelTokenType::Operator(operator) => Some(operator),
+            _ => None
+        }).collect::<Vec<_>>();
+
+        assert_eq!(operators, [KaramelOperatorType::Exponent, KaramelOperatorType::Multiplication]);
+    }
+
+    #[test]
+    fn token_position_1() {
+        let mut parser = Parser::new("a = 1\nb += 2");
+        match parser.parse() {
+            Err(_) => assert_eq!(true, false),
+            _ => ()
+        };
+
+        let token = parser.tokens().iter().find(|token| token.token_type == KaramelTokenType::Operator(KaramelOperatorType::AssignAddition)).unwrap();
+        assert_eq!(token.line, 1);
+        assert_eq!(token.start, 2);
+        assert_eq!(token.end, 4);
+    }
+
+    #[test]
+    fn token_position_2() {
+        let mut parser = Parser::new("a = 1\n  b");
+        match parser.parse() {
+            Err(_) => assert_eq!(true, false),
+            _ => ()
+        };
+
+        let tokens = parser.tokens();
+        assert_eq!(tokens[5].token_type, KaramelTokenType::NewLine(2, KaramelWhitespaceType::Space));
+        assert_eq!(tokens[5].line, 0);
+        assert_eq!(tokens[5].start, 5);
+        assert_eq!(tokens[5].end, 6);
+
+        assert_eq!(tokens[6].line, 1);
+        assert_eq!(tokens[6].start, 2);
+        assert_eq!(tokens[6].end, 3);
+    }
+
+    #[test]
+    fn error_span() {
+        let source = "çıktı = 'şeker\n";
+        let mut parser = Parser::new(source);
+        match parser.parse() {
+            Err(error) => assert_eq!(error.span, Some(11..source.len())),
+            _ => assert_eq!(true, false)
+        };
+
+        let source = "çıktı = ğ + ";
+        let mut parser = Parser::new(source);
+        match parser.parse() {
+            Err(_) => assert_eq!(true, false),
+            _ => ()
+        };
+
+        let syntax = SyntaxParser::new(parser.tokens().to_vec());
+        match syntax.parse() {
+            Err(error) => {
+                assert_eq!(error.span, Some(14..15));
+                assert_eq!(&source[error.span.unwrap()], "+");
+            },
+            _ => assert_eq!(true, false)
+        };
+    }
+
+    #[test]
+    fn borrowed_tokens() {
+        let mut parser = Parser::new("a = [1, 2]\ngç::satıryaz(a)");
+        parser.parse().unwrap();
+
+        let tokens: &[Token] = parser.tokens();
+        let syntax = SyntaxParser::new(tokens);
+        assert!(syntax.parse().is_ok());
+
+        /* Tokens are not copied */
+        assert_eq!(syntax.tokens.as_ptr(), parser.tokens().as_ptr());
+    }
+}
@@ -58,17 +58,23 @@ mod tests {
     test_success!(test_3, "'merhaba dünya", Err(KaramelError {
         error_type: KaramelErrorType::MissingStringDeliminator,
         column: 14,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_success!(test_4, "\"merhaba dünya", Err(KaramelError {
         error_type: KaramelErrorType::MissingStringDeliminator,
         column: 14,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_success!(test_5, "merhaba dünya'", Err(KaramelError {
         error_type: KaramelErrorType::MissingStringDeliminator,
         column: 14,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
 
     test_success!(bool_2, "doğru", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true))))));
@@ -76,18 +82,18 @@ mod tests {
 
     test_success!(dict_1, "{}", Ok(Rc::new(KaramelAstType::Dict(Vec::new()))));
     test_success!(dict_2, "{'1':1}", Ok(Rc::new(KaramelAstType::Dict([Rc::new(KaramelDictItem {
-        key: Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))),
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))))),
         value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
     })].to_vec()))));
     test_success!(dict_3, r#"{
         '1' : 1, 
         '2': 2
 }"#, Ok(Rc::new(KaramelAstType::Dict([Rc::new(KaramelDictItem {
-        key: Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))),
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))))),
         value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
     }),
     Rc::new(KaramelDictItem {
-        key: Rc::new(KaramelPrimative::Text(Rc::new("2".to_string()))),
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("2".to_string()))))),
         value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
     })].to_vec()))));
     test_success!(dict_4, r#"{
@@ -95,15 +101,59 @@ mod tests {
         '2': 2,
         '1': 2
 }"#, Ok(Rc::new(KaramelAstType::Dict([Rc::new(KaramelDictItem {
-        key: Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))),
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))))),
         value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
     }),
     Rc::new(KaramelDictItem {
-        key: Rc::new(KaramelPrimative::Text(Rc::new("2".to_string()))),
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("2".to_string()))))),
         value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
     }),
     Rc::new(KaramelDictItem {
-        key: Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))),
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))))),
+        value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })].to_vec()))));
+    test_success!(dict_5, "{'1':1, }", Ok(Rc::new(KaramelAstType::Dict([Rc::new(KaramelDictItem {
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))))),
+        value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+    })].to_vec()))));
+    test_success!(dict_6, r#"{
+        '1': 1,
+}"#, Ok(Rc::new(KaramelAstType::Dict([Rc::new(KaramelDictItem {
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("1".to_string()))))),
+        value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+    })].to_vec()))));
+    test_success!(dict_7, "{,}", Err(KaramelError {
+        error_type: KaramelErrorType::DictionaryKeyNotValid,
+        column: 2,
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_success!(dict_8, "{'1':1,,}", Err(KaramelError {
+        error_type: KaramelErrorType::DictionaryKeyNotValid,
+        column: 8,
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_success!(dict_9, "{a: 1, b: 2}", Ok(Rc::new(KaramelAstType::Dict([Rc::new(KaramelDictItem {
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("a".to_string()))))),
+        value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+    }),
+    Rc::new(KaramelDictItem {
+        key: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("b".to_string()))))),
+        value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })].to_vec()))));
+    test_success!(dict_10, "{(a): 1, 'b' + 'c': 2}", Ok(Rc::new(KaramelAstType::Dict([Rc::new(KaramelDictItem {
+        key: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+    }),
+    Rc::new(KaramelDictItem {
+        key: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("b".to_string()))))),
+            operator: KaramelOperatorType::Addition,
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("c".to_string())))))
+        }),
         value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
     })].to_vec()))));
     
@@ -118,11 +168,41 @@ mod tests {
     test_success!(list_7, "[123", Err(KaramelError {
         error_type: KaramelErrorType::ArrayNotClosed,
         column: 4,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_success!(list_8, "[data]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Symbol("data".to_string()))].to_vec()))));
+    test_success!(list_9, "[1, 2,]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))].to_vec()))));
+    test_success!(list_10, "[1, 2, ]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))].to_vec()))));
+    test_success!(list_11, "[,]", Err(KaramelError {
+        error_type: KaramelErrorType::InvalidListItem,
+        column: 2,
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_success!(list_12, "[1,,2]", Err(KaramelError {
+        error_type: KaramelErrorType::InvalidListItem,
+        column: 4,
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
+    }));
+    test_success!(list_13, "[a+1, b*2]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        operator: KaramelOperatorType::Addition,
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+    }),
+    Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Symbol("b".to_string())),
+        operator: KaramelOperatorType::Multiplication,
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })].to_vec()))));
 
     test_success!(empty_1, "boş", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Empty)))));
+    test_success!(empty_2, "bos", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Empty)))));
+    test_success!(empty_3, "yok", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Empty)))));
 
     test_success!(symbol_1, "data", Ok(Rc::new(KaramelAstType::Symbol("data".to_string()))));
     test_success!(symbol_2, "data_test", Ok(Rc::new(KaramelAstType::Symbol("data_test".to_string()))));
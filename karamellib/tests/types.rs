@@ -3,6 +3,7 @@ extern crate karamellib;
 #[cfg(test)]
 mod tests {
     use crate::karamellib::types::CharTraits;
+    use crate::karamellib::types::{KaramelKeywordType, KaramelOperatorType};
 
     #[test]
     fn is_new_line() {
@@ -38,5 +39,16 @@ mod tests {
         assert_eq!(true, '\r'.is_whitespace());
         assert_eq!(true, '\t'.is_whitespace());
         assert_eq!(false, '2'.is_whitespace());
+        assert_eq!(true, CharTraits::is_whitespace(&'\u{00A0}'));
+        assert_eq!(true, CharTraits::is_whitespace(&'\u{2009}'));
+        assert_eq!(false, CharTraits::is_whitespace(&'\n'));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn keyword_to_operator() {
+        assert_eq!(KaramelKeywordType::And.to_operator(), Some(KaramelOperatorType::And));
+        assert_eq!(KaramelKeywordType::Modulo.to_operator(), Some(KaramelOperatorType::Modulo));
+        assert_eq!(KaramelKeywordType::If.to_operator(), None);
+        assert_eq!(KaramelKeywordType::Use.to_operator(), None);
+    }
+}
@@ -59,22 +59,30 @@ mod tests {
     test_compare!(unary_9, "+[]", Err(KaramelError {
         error_type: KaramelErrorType::UnaryWorksWithNumber,
         column: 1,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(unary_10, "++100", Err(KaramelError {
         error_type: KaramelErrorType::InvalidUnaryOperation,
         column: 2,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(unary_11, "--100", Err(KaramelError {
         error_type: KaramelErrorType::InvalidUnaryOperation,
         column: 2,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
     test_compare!(unary_12, "--doğru", Err(KaramelError {
         error_type: KaramelErrorType::InvalidUnaryOperation,
         column: 2,
-        line: 0
+        line: 0,
+        span: None,
+        backtrace: Vec::new()
     }));
 
     test_compare!(unary_13, "++data", Ok(Rc::new(KaramelAstType::PrefixUnary { 
@@ -105,5 +113,12 @@ mod tests {
         operator: KaramelOperatorType::Subtraction,
         right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
     })));
+    test_compare!(unary_20, "matris[i][j]", Ok(Rc::new(KaramelAstType::Indexer {
+        body: Rc::new(KaramelAstType::Indexer {
+            body: Rc::new(KaramelAstType::Symbol("matris".to_string())),
+            indexer: Rc::new(KaramelAstType::Symbol("i".to_string()))
+        }),
+        indexer: Rc::new(KaramelAstType::Symbol("j".to_string()))
+    })));
     //test_compare!(unary_19, "doğru değil", Ok(Rc::new(KaramelAstType::SuffixUnary(KaramelOperatorType::Not, Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))))));
 }
\ No newline at end of file
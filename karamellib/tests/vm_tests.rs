@@ -8,6 +8,17 @@ mod tests {
     use crate::karamellib::syntax::*;
     use crate::karamellib::*;
 
+    use crate::karamellib::compiler::context::{ContextCapability, ContextConfig};
+    use crate::karamellib::error::{KaramelDiagnostic, KaramelDiagnosticType, KaramelErrorSeverity, KaramelErrorType, KaramelStackFrame};
+    use crate::karamellib::types::VmObject;
+    use crate::karamellib::vm::executer::{ExecutionParameters, ExecutionSource};
+    use crate::karamellib::compiler::module::{ModuleLoader, ModuleSource};
+    use crate::karamellib::compiler::function::{FunctionParameter, FunctionReference, NativeCall, NativeCallResult};
+    use crate::karamellib::buildin::{Class, Module};
+    use std::cell::RefCell;
+    use std::cmp::Ordering;
+    use std::collections::HashMap;
+    use std::path::Path;
     use std::rc::Rc;
 
     #[warn(unused_macros)]
@@ -167,7 +178,33 @@ mod tests {
     test_last_memory!(vm_51, "empty != empty", KaramelPrimative::Bool(false));
     test_last_memory!(vm_52, "boş == boş", KaramelPrimative::Bool(true));
     test_last_memory!(vm_53, "boş != boş", KaramelPrimative::Bool(false));
+    test_last_memory!(vm_54, "yok == yok", KaramelPrimative::Bool(true));
+    test_last_memory!(vm_54_1, "yok == 0", KaramelPrimative::Bool(false));
+    test_last_memory!(vm_54_2, "yok == yanlış", KaramelPrimative::Bool(false));
+    test_last_memory!(vm_54_3, "yok == ''", KaramelPrimative::Bool(false));
+    test_last_memory!(vm_54_4, "yok != 0", KaramelPrimative::Bool(true));
+    test_last_memory!(vm_54_5, "değil yok", KaramelPrimative::Bool(true));
+    test_last_memory!(vm_54_6, "yok ve doğru", KaramelPrimative::Empty);
+    test_last_memory!(vm_54_7, "yok veya yanlış", KaramelPrimative::Bool(false));
+    test_last_memory!(vm_54_8, "0 veya 5", KaramelPrimative::Number(5.0));
+    test_last_memory!(vm_54_9, "'x' ve 'y'", KaramelPrimative::Text(Rc::new("y".to_string())));
+    test_last_memory!(vm_54_10, "'' ve 'y'", KaramelPrimative::Text(Rc::new("".to_string())));
+    test_last_memory!(vm_54_11, "'x' veya 'y'", KaramelPrimative::Text(Rc::new("x".to_string())));
     test_last_memory!(vm_55, "test_1 == test_2", KaramelPrimative::Bool(true));
+    test_last_memory!(vm_55_1, "10 mod 3", KaramelPrimative::Number(1.0));
+    test_last_memory!(vm_55_2, "-10 mod 3", KaramelPrimative::Number(-1.0));
+    test_last_memory!(vm_55_3, "7.5 mod 2", KaramelPrimative::Number(1.5));
+    test_last_memory!(vm_55_4, "10 mod 0", KaramelPrimative::Empty);
+    test_last_memory!(vm_55_5, "'erhan' mod 3", KaramelPrimative::Empty);
+    test_last_memory!(vm_55_6, "10 mod doğru", KaramelPrimative::Empty);
+    test_last_memory!(vm_55_7, "1 + 10 mod 4", KaramelPrimative::Number(3.0));
+    test_last_memory!(vm_55_8, "2 ** 8", KaramelPrimative::Number(256.0));
+    test_last_memory!(vm_55_9, "2 ** 3 ** 2", KaramelPrimative::Number(512.0));
+    test_last_memory!(vm_55_10, "2 ** -1", KaramelPrimative::Number(0.5));
+    test_last_memory!(vm_55_11, "3 * 2 ** 2", KaramelPrimative::Number(12.0));
+    test_last_memory!(vm_55_12, "'2' ** 2", KaramelPrimative::Empty);
+    test_last_memory!(vm_55_13, "2 + 3 * 4 ** 2", KaramelPrimative::Number(50.0));
+    test_last_memory!(vm_55_14, "10 - 2 - 3 mod 2", KaramelPrimative::Number(7.0));
     test_variable_value!(vm_56, "text", "text = 1024", KaramelPrimative::Number(1024.0));
     test_variable_value!(vm_57, "result", r#"text = 1024
 result = text *2"#, KaramelPrimative::Number(2048.0));
@@ -188,7 +225,7 @@ erhan=!erhan"#, KaramelPrimative::Bool(false));
 erhan=!erhan"#, KaramelPrimative::Bool(true));
     test_variable_value!(vm_63, "erhan", r#"erhan=!yanlış"#, KaramelPrimative::Bool(true));
     test_variable_value!(vm_64, "erhan", r#"erhan=!doğru"#, KaramelPrimative::Bool(false));
-    test_variable_value!(vm_65, "erhan", r#"erhan=!-100"#, KaramelPrimative::Bool(true));
+    test_variable_value!(vm_65, "erhan", r#"erhan=!-100"#, KaramelPrimative::Bool(false));
     test_variable_value!(vm_66, "erhan", r#"erhan=1
 barış=erhan++"#, KaramelPrimative::Number(2.0));
     test_variable_value!(vm_67, "barış", r#"erhan=1
@@ -408,4 +445,1135 @@ fonk Fibonacci(n):
 hataayıklama::doğrula(Fibonacci(10), 55)
 hataayıklama::doğrula(Fibonacci(20), 6765)
 "#);
-}
\ No newline at end of file
+test_variable_value!(vm_108, "toplam", r#"
+kayıtlar = {'a': 1, 'b': 2, 'c': 3}
+toplam = 0
+her anahtar, değer kayıtlar içinde:
+    toplam += değer
+"#, KaramelPrimative::Number(6.0));
+test_variable_value!(vm_109, "toplam", r#"
+toplam = 0
+her i [1, 2, 3, 4] içinde:
+    i == 2 ise:
+        devam
+    toplam += i
+"#, KaramelPrimative::Number(8.0));
+execute!(vm_110, r#"
+fonk koşul(değer):
+    değer ise:
+        döndür doğru
+    veya:
+        döndür yanlış
+
+fonk aynı_mı(değer, beklenen):
+    hataayıklama::doğrula(koşul(değer), beklenen)
+    hataayıklama::doğrula(değil değil değer, beklenen)
+    hataayıklama::doğrula(koşul(değer ve doğru), beklenen)
+    hataayıklama::doğrula(koşul(değer veya yanlış), beklenen)
+
+aynı_mı([1], doğru)
+aynı_mı([], yanlış)
+aynı_mı({'a': 1}, doğru)
+aynı_mı({}, yanlış)
+aynı_mı('karamel', doğru)
+aynı_mı('', yanlış)
+aynı_mı(1, doğru)
+aynı_mı(0, yanlış)
+aynı_mı(boş, yanlış)
+"#);
+execute!(vm_111, r#"
+fonk topla(bir, iki): gç::satıryaz(bir); döndür bir + iki
+a = 1; b = 2
+hataayıklama::doğrula(topla(a, b), 3)
+"#);
+test_variable_value!(vm_112, "toplam", r#"
+toplam = (1 +
+    2 *
+  3)
+"#, KaramelPrimative::Number(7.0));
+test_variable_value!(vm_113, "ığdır", r#"
+İsim = 1
+isim = 2
+IĞDIR = 3
+ığdır = İsim + isim * IĞDIR
+Sayı = 10
+"#, KaramelPrimative::Number(7.0));
+test_variable_value!(vm_114, "Sayı", r#"
+Sayı = 10
+"#, KaramelPrimative::Number(10.0));
+execute!(vm_115, r#"
+fonk çıkar(bir, iki): döndür iki - bir
+hataayıklama::doğrula(çıkar(1, 10), 9)
+"#);
+execute!(vm_116, r#"
+fonk topla(bir, iki):
+    toplam = bir + iki
+    döndür toplam
+hataayıklama::doğrula(topla(1, 2), 3)
+"#);
+execute!(vm_117, r#"
+a = 'kara'
+b = a + a
+hataayıklama::doğrula(b, 'karakara')
+hataayıklama::doğrula(a, 'kara')
+hataayıklama::doğrula(a + a + a, 'karakarakara')
+hataayıklama::doğrula(b * 2, 'karakarakarakara')
+"#);
+
+/* Not uses the truthiness of every type */
+execute!(vm_118, r#"
+hataayıklama::doğrula(!yok, doğru)
+hataayıklama::doğrula(!boş, doğru)
+hataayıklama::doğrula(!doğru, yanlış)
+hataayıklama::doğrula(!yanlış, doğru)
+hataayıklama::doğrula(!0, doğru)
+hataayıklama::doğrula(!1, yanlış)
+hataayıklama::doğrula(!12.5, yanlış)
+hataayıklama::doğrula(!0.0, doğru)
+hataayıklama::doğrula(!-1, yanlış)
+hataayıklama::doğrula(!-0.5, yanlış)
+hataayıklama::doğrula(!'', doğru)
+hataayıklama::doğrula(!'karamel', yanlış)
+hataayıklama::doğrula(![], doğru)
+hataayıklama::doğrula(![1], yanlış)
+hataayıklama::doğrula(![yok], yanlış)
+hataayıklama::doğrula(!{}, doğru)
+hataayıklama::doğrula(!{'a': 1}, yanlış)
+"#);
+execute!(vm_119, r#"
+fonk fonksiyon(): döndür 1
+liste_1 = [1]
+sözlük_1 = {}
+hataayıklama::doğrula(değil yok, doğru)
+hataayıklama::doğrula(değil [1], yanlış)
+hataayıklama::doğrula(değil '', doğru)
+hataayıklama::doğrula(değil liste_1, yanlış)
+hataayıklama::doğrula(değil sözlük_1, doğru)
+hataayıklama::doğrula(değil fonksiyon, yanlış)
+hataayıklama::doğrula(!!liste_1, doğru)
+hataayıklama::doğrula(!!sözlük_1, yanlış)
+"#);
+
+/* Right side is not executed when the left side decides the result */
+execute!(vm_120, r#"
+fonk değer(sonuç): döndür sonuç
+fonk çalışmamalı():
+    hataayıklama::doğrula(doğru, yanlış)
+isim = yok
+hataayıklama::doğrula(isim veya 'varsayılan', 'varsayılan')
+hataayıklama::doğrula(değer(1) veya çalışmamalı(), 1)
+hataayıklama::doğrula(değer(0) ve çalışmamalı(), 0)
+hataayıklama::doğrula(değer(0) veya değer(2), 2)
+hataayıklama::doğrula([] ve 1, [])
+hataayıklama::doğrula(1 ve 2 ve 3, 3)
+hataayıklama::doğrula(0 veya '' veya 'son', 'son')
+"#);
+
+execute!(vm_121, r#"
+fonk pozitif(sayı_1): döndür sayı_1 > 0
+sayılar = [1, 2, 3]
+karışık = [-1, 2, -3]
+negatifler = [-1, -2]
+hataayıklama::doğrula(sayılar.hepsi(pozitif), doğru)
+hataayıklama::doğrula(sayılar.herhangi(pozitif), doğru)
+hataayıklama::doğrula(karışık.hepsi(pozitif), yanlış)
+hataayıklama::doğrula(karışık.herhangi(pozitif), doğru)
+hataayıklama::doğrula(negatifler.hepsi(pozitif), yanlış)
+hataayıklama::doğrula(negatifler.herhangi(pozitif), yanlış)
+hataayıklama::doğrula([].hepsi(pozitif), doğru)
+hataayıklama::doğrula([].herhangi(pozitif), yanlış)
+"#);
+
+execute!(vm_128, r#"
+fonk topla(bir, iki, üç):
+    döndür bir + iki * 10 + üç * 100
+hataayıklama::doğrula(topla(*[1, 2, 3]), 321)
+sayılar = [2, 3]
+hataayıklama::doğrula(topla(1, *sayılar), 321)
+hataayıklama::doğrula(topla(*[1], 2, *[3]), 321)
+fonksiyon = topla
+hataayıklama::doğrula(fonksiyon(*[1, 2, 3]), 321)
+hataayıklama::doğrula(baz::eşleştir(*[[1, 2], [3, 4]]), [[1, 3], [2, 4]])
+elemanlar = [1]
+elemanlar.ekle(*[2])
+hataayıklama::doğrula(elemanlar, [1, 2])
+"#);
+
+    #[test]
+    fn spread_error_1() {
+        let result = run_with_context(r#"
+fonk topla(bir, iki, üç):
+    döndür bir + iki + üç
+topla(*[1, 2])
+"#, &mut KaramelCompilerContext::new());
+        assert_eq!(result.err(), Some(KaramelErrorType::FunctionArgumentNotMatching { function: "topla".to_string(), expected: 3, found: 2 }));
+    }
+
+    #[test]
+    fn spread_error_2() {
+        let result = run_with_context("gç::satıryaz(*'yazı')", &mut KaramelCompilerContext::new());
+        assert_eq!(result.err(), Some(KaramelErrorType::SpreadArgumentNotValid));
+    }
+
+execute!(vm_125, r#"
+a = 1
+b = 2
+hataayıklama::doğrula([1+1, 2*2], [2, 4])
+hataayıklama::doğrula([a+1, b*2, [a, b]], [2, 4, [1, 2]])
+"#);
+
+execute!(vm_126, r#"
+matris = [[1, 2], [3, 4]]
+hataayıklama::doğrula(matris[1][0], 3)
+hataayıklama::doğrula([[1, 2], [3, 4]][0][1], 2)
+matris[0][1] = 9
+hataayıklama::doğrula(matris, [[1, 9], [3, 4]])
+kayıt = {'a': [1, {'b': 5}]}
+hataayıklama::doğrula(kayıt['a'][1]['b'], 5)
+"#);
+
+execute!(vm_127, r#"
+hataayıklama::doğrula("istanbul".büyükharf(), "İSTANBUL")
+hataayıklama::doğrula("IĞDIR".küçükharf(), "ığdır")
+hataayıklama::doğrula("Karamel".buyukharf(), "KARAMEL")
+hataayıklama::doğrula("Karamel".kucukharf(), "karamel")
+"#);
+
+    /* Programs without any statement are executed without an error */
+    test_last_memory!(empty_program_1, "", KaramelPrimative::Empty);
+    test_last_memory!(empty_program_2, "  \n\n    \n", KaramelPrimative::Empty);
+    test_last_memory!(empty_program_3, "// yorum\n   /* diğer yorum */\n", KaramelPrimative::Empty);
+
+    /* Return inside of the loops leaves the function, code after the loop is not executed */
+    #[test]
+    fn return_from_loop_1() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+fonk bul(elemanlar, aranan):
+    sıra = 0
+    döngü sıra < elemanlar.uzunluk():
+        elemanlar[sıra] == aranan ise:
+            döndür sıra
+        sıra += 1
+    hataayıklama::doğrula(doğru, yanlış)
+
+fonk iç_içe():
+    i = 0
+    döngü i < 3:
+        her j [0, 1, 2] içinde:
+            i == 1 ve j == 2 ise:
+                döndür i * 10 + j
+        i += 1
+    hataayıklama::doğrula(doğru, yanlış)
+
+toplam = 0
+sayaç = 0
+döngü sayaç < 100:
+    toplam += bul([5, 6, 7], 6) + iç_içe()
+    sayaç += 1
+hataayıklama::doğrula(toplam, 1300)
+"#, &mut context).unwrap();
+
+        /* Stack of the loops is dropped by the return */
+        assert_eq!(result.len(), context.storages[0].variables.len());
+    }
+
+    /* Items are evaluated from left to right */
+    #[test]
+    fn list_order_1() {
+        let result = run_with_context(r#"
+c = 1
+e = [c++, c++, ++c]
+hataayıklama::doğrula(e, [1, 2, 4])
+"#, &mut KaramelCompilerContext::new());
+        assert!(result.is_ok());
+    }
+
+/* Elements after the deciding one are not passed to the predicate */
+execute!(vm_122, r#"
+fonk kontrol(değer):
+    değer == 'dur' ise:
+        hataayıklama::doğrula(doğru, yanlış)
+    döndür değer
+hataayıklama::doğrula([1, 0, 'dur'].hepsi(kontrol), yanlış)
+hataayıklama::doğrula([0, 1, 'dur'].herhangi(kontrol), doğru)
+"#);
+
+execute!(vm_123, r#"
+hataayıklama::doğrula(baz::eşleştir([1, 2, 3], ['a', 'b']), [[1, 'a'], [2, 'b']])
+hataayıklama::doğrula(baz::eşleştir(['a'], [1, 2]), [['a', 1]])
+hataayıklama::doğrula(baz::eşleştir([], [1, 2]), [])
+"#);
+
+    #[test]
+    fn zip_error_1() {
+        let result = run_with_context("baz::eşleştir([1], 'a')", &mut KaramelCompilerContext::new());
+        assert_eq!(result.err(), Some(KaramelErrorType::FunctionExpectedThatParameterType { function: "eşleştir".to_string(), expected: "Liste".to_string() }));
+    }
+
+execute!(vm_124, r#"
+anahtar = 'soyad'
+kayıt = {ad: 'erhan', (anahtar): 'barış', 'y' + 'aş': 30, ad: 'karamel'}
+hataayıklama::doğrula(kayıt['ad'], 'karamel')
+hataayıklama::doğrula(kayıt['soyad'], 'barış')
+hataayıklama::doğrula(kayıt['yaş'], 30)
+hataayıklama::doğrula(kayıt.uzunluk(), 3)
+"#);
+
+    #[test]
+    fn dict_key_error_1() {
+        let result = run_with_context("anahtar = 1\nkayıt = {(anahtar): 1}", &mut KaramelCompilerContext::new());
+        assert_eq!(result.err(), Some(KaramelErrorType::DictionaryKeyNotValid));
+    }
+
+execute!(vm_129, r#"
+kaynak = [1, [2, 3], {'a': [4]}]
+kopya = baz::kopyala(kaynak)
+hataayıklama::doğrula(kopya, kaynak)
+kopya[0] = 10
+kopya[1].ekle(5)
+kopya[2]['a'].ekle(6)
+kopya[2]['b'] = 7
+hataayıklama::doğrula(kaynak, [1, [2, 3], {'a': [4]}])
+hataayıklama::doğrula(kopya, [10, [2, 3, 5], {'a': [4, 6], 'b': 7}])
+hataayıklama::doğrula(baz::kopyala(1), 1)
+hataayıklama::doğrula(baz::kopyala('yazı'), 'yazı')
+"#);
+
+execute!(vm_130, r#"
+fonk bir(): döndür 1
+fonk iki(): dondur 2
+hataayıklama::doğrula(bir() + iki(), 3)
+"#);
+
+execute!(vm_131, r#"
+metin = "çayçay"
+hataayıklama::doğrula(metin.bul("y"), 2)
+hataayıklama::doğrula(metin.sondanbul("y"), 5)
+hataayıklama::doğrula(metin.bul("su"), -1)
+"#);
+
+execute!(vm_132, r#"
+kayıtlar = {'ad': 'erhan', 'soyad': 'barış', 'yaş': 30}
+anahtar_listesi = kayıtlar.anahtarlar()
+değer_listesi = kayıtlar.değerler()
+hataayıklama::doğrula(kayıtlar.uzunluk(), 3)
+hataayıklama::doğrula(anahtar_listesi.uzunluk(), 3)
+hataayıklama::doğrula(değer_listesi.uzunluk(), 3)
+hataayıklama::doğrula(kayıtlar.getir(anahtar_listesi[2]), değer_listesi[2])
+hataayıklama::doğrula(kayıtlar.içeriyormu('yaş'), doğru)
+hataayıklama::doğrula(kayıtlar.içeriyormu('adres'), yanlış)
+hataayıklama::doğrula({}.değerler(), [])
+"#);
+
+execute!(vm_133, r#"
+elemanlar = []
+aynı_liste = elemanlar
+hataayıklama::doğrula(elemanlar.ekle('karamel'), 0)
+hataayıklama::doğrula(aynı_liste, ['karamel'])
+hataayıklama::doğrula(elemanlar.çıkar(), 'karamel')
+hataayıklama::doğrula(aynı_liste.uzunluk(), 0)
+hataayıklama::doğrula(elemanlar.cikar(), boş)
+"#);
+
+execute!(vm_134, r#"
+sonuç = yanlış
+-1 ise:
+    sonuç = doğru
+hataayıklama::doğrula(sonuç, doğru)
+hataayıklama::doğrula(-1 ve 'karamel', 'karamel')
+hataayıklama::doğrula(0 veya -2, -2)
+"#);
+
+execute!(vm_135, r#"
+sayılar = [3, -2, 10]
+hataayıklama::doğrula(sayılar.sırala(), [-2, 3, 10])
+hataayıklama::doğrula(sayılar.sirala(doğru), [10, 3, -2])
+hataayıklama::doğrula(sayılar, [3, -2, 10])
+hataayıklama::doğrula(['kedi', 'armut'].sırala(), ['armut', 'kedi'])
+"#);
+
+/* NaN follows IEEE equality and it is true in conditions because it is not zero */
+execute!(vm_136, r#"
+nan = (0 - 1) ** 0.5
+hataayıklama::doğrula(nan == nan, yanlış)
+hataayıklama::doğrula(nan != nan, doğru)
+hataayıklama::doğrula(nan == 0, yanlış)
+hataayıklama::doğrula(!nan, yanlış)
+sonuç = yanlış
+nan ise:
+    sonuç = doğru
+hataayıklama::doğrula(sonuç, doğru)
+"#);
+
+execute!(vm_137, r#"
+fonk ikikatı(değer): döndür değer * 2
+fonk sıfırdeğil(değer): döndür değer != 0
+sayılar = [1, 2, 3]
+hataayıklama::doğrula(sayılar.harita(ikikatı), [2, 4, 6])
+hataayıklama::doğrula(sayılar, [1, 2, 3])
+hataayıklama::doğrula([0, 1, 0, 2, 0].filtrele(sıfırdeğil), [1, 2])
+hataayıklama::doğrula([0, 1, 0, 2].filtrele(ikikatı), [1, 2])
+hataayıklama::doğrula([].harita(ikikatı), [])
+hataayıklama::doğrula([[1], [2]].harita(baz::kopyala), [[1], [2]])
+hataayıklama::doğrula(sayılar.harita(ikikatı).filtrele(sıfırdeğil).uzunluk(), 3)
+"#);
+
+    #[test]
+    fn map_not_callable() {
+        let result = run_with_context("[1, 2].harita(1)", &mut KaramelCompilerContext::new());
+        assert!(matches!(result.err(), Some(KaramelErrorType::NotCallable(_))));
+    }
+
+    /* Instructions of the callbacks are counted with the instructions of the caller */
+    #[test]
+    fn map_instruction_limit() {
+        for method in ["harita", "filtrele"].iter() {
+            let code = format!(r#"
+fonk bekle(değer):
+    sayaç = 0
+    döngü sayaç != 100:
+        sayaç += 1
+    döndür değer
+[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20].{}(bekle)
+"#, method);
+            assert!(run_with_config(&code, ContextConfig { instruction_limit: None, ..limited_config() }).is_ok());
+            assert_eq!(run_with_config(&code, limited_config()).err(), Some(KaramelErrorType::InstructionLimitExceeded(10_000)));
+        }
+    }
+
+/* Lists and dictionaries that contain themselves are copied with the same shape */
+execute!(vm_138, r#"
+elemanlar = [1]
+elemanlar.ekle(elemanlar)
+kopya = baz::kopyala(elemanlar)
+kopya.ekle(2)
+hataayıklama::doğrula(kopya[1].uzunluk(), 3)
+hataayıklama::doğrula(elemanlar.uzunluk(), 2)
+hataayıklama::doğrula(elemanlar[1].uzunluk(), 2)
+kayıt = {'a': 1}
+kayıt['kendi'] = kayıt
+kayıt_kopya = baz::kopyala(kayıt)
+kayıt_kopya['b'] = 2
+hataayıklama::doğrula(kayıt_kopya['kendi'].uzunluk(), 3)
+hataayıklama::doğrula(kayıt['kendi'].uzunluk(), 2)
+"#);
+
+    fn run_with_config(code: &str, config: ContextConfig) -> Result<Vec<VmObject>, KaramelErrorType> {
+        run_with_context(code, &mut KaramelCompilerContext::with_config(config))
+    }
+
+    fn run_with_context(code: &str, context: &mut KaramelCompilerContext) -> Result<Vec<VmObject>, KaramelErrorType> {
+        let mut parser = Parser::new(code);
+        parser.parse().map_err(KaramelErrorType::from)?;
+
+        let syntax = SyntaxParser::new(parser.tokens().to_vec());
+        let ast = syntax.parse().map_err(KaramelErrorType::from)?;
+
+        let opcode_compiler = InterpreterCompiler {};
+        opcode_compiler.compile(ast, context).map_err(KaramelErrorType::from)?;
+        unsafe { interpreter::run_vm(context, false, false) }
+    }
+
+    fn limited_config() -> ContextConfig {
+        ContextConfig {
+            script_path: Some(".".to_string()),
+            search_paths: vec!["moduller".to_string()],
+            capabilities: ContextCapability::IO,
+            max_call_depth: Some(16),
+            instruction_limit: Some(10_000),
+            file_extension: ".k".to_string(),
+            shadowing_warnings: false
+        }
+    }
+
+    #[test]
+    fn config_1() {
+        let result = run_with_config(r#"
+fonk topla(sayı_1, sayı_2): döndür sayı_1 + sayı_2
+gç::satıryaz(topla(1, 2))
+hataayıklama::doğrula(topla(1, 2), 3)
+"#, limited_config());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn config_2() {
+        let result = run_with_config(r#"
+sonsuz:
+    a = 1
+"#, limited_config());
+        assert_eq!(result.err(), Some(KaramelErrorType::InstructionLimitExceeded(10_000)));
+    }
+
+    #[test]
+    fn config_3() {
+        let result = run_with_config(r#"
+fonk sonsuz_çağrı(sayı_1): döndür 1 + sonsuz_çağrı(sayı_1 + 1)
+sonsuz_çağrı(1)
+"#, limited_config());
+        assert_eq!(result.err(), Some(KaramelErrorType::CallDepthExceeded(16)));
+    }
+
+    #[test]
+    fn config_4() {
+        let result = run_with_config("topla yükle", limited_config());
+        assert_eq!(result.err(), Some(KaramelErrorType::ModuleLoadingNotAllowed("topla".to_string())));
+    }
+
+    #[test]
+    fn config_5() {
+        let mut config = limited_config();
+        config.capabilities = ContextCapability::NONE;
+        assert!(run_with_config("gç::satıryaz(1)", config).is_err());
+        assert!(run_with_config("gç::satıryaz(1)", limited_config()).is_ok());
+    }
+
+    /* Every call stays under the limit, functions called from native functions share the count of the caller */
+    #[test]
+    fn config_6() {
+        let code = r#"
+fonk bekle(değer):
+    sayaç = 0
+    döngü sayaç != 100:
+        sayaç += 1
+    döndür doğru
+hataayıklama::doğrula([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20].hepsi(bekle), doğru)
+"#;
+        assert!(run_with_config(code, ContextConfig { instruction_limit: None, ..limited_config() }).is_ok());
+        assert_eq!(run_with_config(code, limited_config()).err(), Some(KaramelErrorType::InstructionLimitExceeded(10_000)));
+    }
+
+    #[test]
+    fn assign_function_1() {
+        let result = run_with_config(r#"
+fonk topla(sayı_1, sayı_2): döndür sayı_1 + sayı_2
+topla = 5
+"#, ContextConfig::default());
+        assert_eq!(result.err(), Some(KaramelErrorType::CannotAssignToFunction("topla".to_string())));
+    }
+
+    #[test]
+    fn assign_function_2() {
+        let result = run_with_config(r#"
+fonk topla(sayı_1, sayı_2): döndür sayı_1 + sayı_2
+fonk test():
+    topla += 1
+"#, ContextConfig::default());
+        assert_eq!(result.err(), Some(KaramelErrorType::CannotAssignToFunction("topla".to_string())));
+    }
+
+    #[test]
+    fn assign_function_3() {
+        let result = run_with_config(r#"
+satıryaz = 3
+gç::satıryaz(satıryaz)
+hataayıklama::doğrula(satıryaz, 3)
+"#, ContextConfig::default());
+        assert!(result.is_ok());
+    }
+
+    fn compile_diagnostics(code: &str) -> Result<Vec<KaramelDiagnostic>, KaramelErrorType> {
+        compile_diagnostics_with_config(code, ContextConfig::default())
+    }
+
+    fn compile_diagnostics_with_config(code: &str, config: ContextConfig) -> Result<Vec<KaramelDiagnostic>, KaramelErrorType> {
+        let mut parser = Parser::new(code);
+        parser.parse().map_err(KaramelErrorType::from)?;
+
+        let syntax = SyntaxParser::new(parser.tokens().to_vec());
+        let ast = syntax.parse().map_err(KaramelErrorType::from)?;
+
+        let opcode_compiler = InterpreterCompiler {};
+        let mut context = KaramelCompilerContext::with_config(config);
+        opcode_compiler.compile(ast, &mut context).map_err(KaramelErrorType::from)?;
+        Ok(context.diagnostics)
+    }
+
+    #[test]
+    fn diagnostics_channel() {
+        let diagnostics = compile_diagnostics(r#"
+toplam = 1
+gç::satıryaz(toplam)
+"#).unwrap();
+        assert_eq!(diagnostics, Vec::new());
+
+        let warning = KaramelDiagnostic::warning(KaramelDiagnosticType::UnusedVariable("toplam".to_string()), 1, 0);
+        assert_eq!(warning.severity, KaramelErrorSeverity::Minor);
+        assert_eq!(warning.message(), "'toplam' değişkeni tanımlandı fakat kullanılmadı");
+    }
+
+    #[test]
+    fn diagnostic_1() {
+        let diagnostics = compile_diagnostics("kullanılmayan = 1").unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].diagnostic_type, KaramelDiagnosticType::UnusedVariable("kullanılmayan".to_string()));
+        assert_eq!(diagnostics[0].severity, KaramelErrorSeverity::Minor);
+        assert_eq!(diagnostics[0].message(), "'kullanılmayan' değişkeni tanımlandı fakat kullanılmadı");
+    }
+
+    #[test]
+    fn diagnostic_2() {
+        let diagnostics = compile_diagnostics(r#"
+fonk test(kullanılmayan_parametre):
+    döndür 1
+her eleman [1, 2] içinde:
+    gç::satıryaz(eleman)
+"#).unwrap();
+        assert_eq!(diagnostics, Vec::new());
+    }
+
+    #[test]
+    fn diagnostic_3() {
+        let diagnostics = compile_diagnostics(r#"
+toplam = 10
+sonuç = 0
+sonuc = toplam * 2
+gç::satıryaz(sonuc)
+"#).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].diagnostic_type, KaramelDiagnosticType::UnusedVariable("sonuç".to_string()));
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 0));
+    }
+
+    #[test]
+    fn diagnostic_4() {
+        let diagnostics = compile_diagnostics(r#"
+fonk test(sayı_1):
+    ara_değer = sayı_1 * 2
+    döndür sayı_1
+test(1)
+"#).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].diagnostic_type, KaramelDiagnosticType::UnusedVariable("ara_değer".to_string()));
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 4));
+    }
+
+    #[test]
+    fn diagnostic_5() {
+        let code = r#"
+sayaç = 1
+fonk artır(sayaç):
+    döndür sayaç + 1
+gç::satıryaz(artır(sayaç))
+"#;
+        assert_eq!(compile_diagnostics(code).unwrap(), Vec::new());
+
+        let mut config = ContextConfig::default();
+        config.shadowing_warnings = true;
+        let diagnostics = compile_diagnostics_with_config(code, config).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].diagnostic_type, KaramelDiagnosticType::ShadowedVariable("sayaç".to_string(), "artır".to_string()));
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 11));
+    }
+
+    #[test]
+    fn diagnostic_6() {
+        let diagnostics = compile_diagnostics(r#"
+fonk birinci():
+    ara_değer = 1
+    döndür ara_değer
+fonk ikinci():
+    ara_değer = 2
+    döndür 0
+gç::satıryaz(birinci() + ikinci())
+"#).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].diagnostic_type, KaramelDiagnosticType::UnusedVariable("ara_değer".to_string()));
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (5, 4));
+    }
+
+    #[test]
+    fn diagnostic_7() {
+        let mut config = ContextConfig::default();
+        config.shadowing_warnings = true;
+        let diagnostics = compile_diagnostics_with_config(r#"
+toplam = 1
+fonk hesapla():
+    toplam = 2
+    döndür toplam
+gç::satıryaz(hesapla() + toplam)
+"#, config).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].diagnostic_type, KaramelDiagnosticType::ShadowedVariable("toplam".to_string(), "hesapla".to_string()));
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (3, 4));
+    }
+
+    struct MemoryLoader {
+        modules: HashMap<String, String>
+    }
+
+    impl ModuleLoader for MemoryLoader {
+        fn load(&self, path: &[String], _: Option<&Path>, _: &KaramelCompilerContext) -> Result<ModuleSource, KaramelErrorType> {
+            match self.modules.get(&path.join("::")) {
+                Some(source) => Ok(ModuleSource::new(path, source.to_string())),
+                None => Err(KaramelErrorType::ModuleParseError {
+                    name: path.join("::"),
+                    error: format!("{} bulunamadı", path.join("::"))
+                })
+            }
+        }
+    }
+
+    fn memory_loader() -> Rc<MemoryLoader> {
+        let mut modules = HashMap::new();
+        modules.insert("matematik".to_string(), "fonk kare(sayı_1): döndür sayı_1 * sayı_1".to_string());
+        modules.insert("araçlar::hesap".to_string(), r#"
+matematik yükle
+fonk kare_topla(sayı_1, sayı_2): döndür matematik::kare(sayı_1) + matematik::kare(sayı_2)"#.to_string());
+
+        Rc::new(MemoryLoader { modules })
+    }
+
+    #[test]
+    fn module_loader_1() {
+        let mut context = KaramelCompilerContext::new();
+        context.module_loader = memory_loader();
+        let result = run_with_context(r#"
+araçlar::hesap yükle
+hataayıklama::doğrula(araçlar::hesap::kare_topla(2, 3), 13)
+"#, &mut context);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn module_loader_2() {
+        let mut context = KaramelCompilerContext::new();
+        context.module_loader = memory_loader();
+        let result = run_with_context("veritabanı yükle", &mut context);
+        assert_eq!(result.err(), Some(KaramelErrorType::ModuleParseError {
+            name: "veritabanı".to_string(),
+            error: "veritabanı bulunamadı".to_string()
+        }));
+    }
+
+    struct HostModule {
+        methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+        path: Vec<String>
+    }
+
+    impl Module for HostModule {
+        fn get_module_name(&self) -> String { "sunucu".to_string() }
+        fn get_path(&self) -> &Vec<String> { &self.path }
+        fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> { self.methods.borrow().get(name).cloned() }
+        fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> { None }
+        fn get_methods(&self) -> Vec<Rc<FunctionReference>> { self.methods.borrow().values().cloned().collect() }
+        fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> { HashMap::new() }
+        fn get_classes(&self) -> Vec<Rc<dyn Class>> { Vec::new() }
+        fn get_constant(&self, name: &str) -> Option<Rc<KaramelPrimative>> {
+            match name {
+                "sürüm" => Some(Rc::new(KaramelPrimative::Number(3.0))),
+                _ => None
+            }
+        }
+    }
+
+    fn double(parameter: FunctionParameter) -> NativeCallResult {
+        match &*parameter.iter().next().unwrap().deref() {
+            KaramelPrimative::Number(number) => Ok(VmObject::from(number * 2.0)),
+            _ => Ok(VmObject::from(0.0))
+        }
+    }
+
+    /* Sorts the list with the comparator of the script, it returns a number like 'sol - sağ' */
+    fn sort(parameter: FunctionParameter) -> NativeCallResult {
+        let mut arguments = parameter.iter();
+        let (list, comparator) = (*arguments.next().unwrap(), *arguments.next().unwrap());
+        let mut items = match &*list.deref() {
+            KaramelPrimative::List(items) => items.borrow().clone(),
+            _ => return Ok(VmObject::from(Vec::<VmObject>::new()))
+        };
+
+        let mut error = None;
+        items.sort_by(|left, right| match parameter.call(comparator, &[*left, *right]) {
+            Ok(result) => result.as_number().unwrap_or(0.0).partial_cmp(&0.0).unwrap_or(Ordering::Equal),
+            Err(call_error) => {
+                error = Some(call_error);
+                Ordering::Equal
+            }
+        });
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(VmObject::from(items))
+        }
+    }
+
+    fn host_module() -> Rc<HostModule> {
+        let module = Rc::new(HostModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["sunucu".to_string()]
+        });
+        module.methods.borrow_mut().insert("iki_kat".to_string(), FunctionReference::native_function(double as NativeCall, "iki_kat".to_string(), module.clone()));
+        module.methods.borrow_mut().insert("sırala".to_string(), FunctionReference::native_function(sort as NativeCall, "sırala".to_string(), module.clone()));
+        module
+    }
+
+    #[test]
+    fn register_module_1() {
+        let mut context = KaramelCompilerContext::new();
+        context.register_module("sunucu", host_module());
+        let result = run_with_context(r#"
+sunucu yükle
+hataayıklama::doğrula(sunucu::iki_kat(21), 42)
+"#, &mut context);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn register_module_2() {
+        let mut context = KaramelCompilerContext::new();
+        context.register_module("sunucu", host_module());
+        let result = run_with_context("sunucu::iki_kat(21)", &mut context);
+        assert_eq!(result.err(), Some(KaramelErrorType::FunctionNotFound("iki_kat".to_string())));
+    }
+
+    #[test]
+    fn module_constant_1() {
+        let mut context = KaramelCompilerContext::new();
+        context.register_module("sunucu", host_module());
+        let result = run_with_context(r#"
+sunucu yükle
+sürüm = sunucu::sürüm
+hataayıklama::doğrula(sürüm, 3)
+hataayıklama::doğrula(sunucu::iki_kat(sunucu::sürüm), 6)
+"#, &mut context);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn module_constant_2() {
+        let mut context = KaramelCompilerContext::new();
+        context.register_module("sunucu", host_module());
+        let result = run_with_context("sunucu yükle\na = sunucu::bilinmeyen", &mut context);
+        assert_eq!(result.err(), Some(KaramelErrorType::FunctionNotFound("bilinmeyen".to_string())));
+    }
+
+    #[test]
+    fn tail_call_1() {
+        let config = ContextConfig { instruction_limit: None, ..limited_config() };
+        let result = run_with_config(r#"
+fonk geri_say(adet, toplam):
+    adet == 0 ise:
+        döndür toplam
+    veya:
+        döndür geri_say(adet - 1, toplam + 1)
+hataayıklama::doğrula(geri_say(100000, 0), 100000)
+"#, config);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn tail_call_2() {
+        let result = run_with_config(r#"
+fonk topla(adet):
+    adet == 0 ise:
+        döndür 0
+    döndür adet + topla(adet - 1)
+topla(100)
+"#, limited_config());
+        assert_eq!(result.err(), Some(KaramelErrorType::CallDepthExceeded(16)));
+    }
+
+    #[test]
+    fn method_cache_1() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+fonk uzunluğu(değer): döndür değer.uzunluk()
+hataayıklama::doğrula(uzunluğu('karamel'), 7)
+hataayıklama::doğrula(uzunluğu([1, 2, 3]), 3)
+hataayıklama::doğrula(uzunluğu({'ad': 'erhan'}), 1)
+hataayıklama::doğrula(uzunluğu('kpd'), 3)
+"#, &mut context);
+        assert!(result.is_ok());
+
+        /* Only one call site is used and the last receiver was a text */
+        let caches = context.method_caches.iter().flatten().collect::<Vec<_>>();
+        assert_eq!(caches.len(), 1);
+        assert_eq!(caches[0].discriminant, KaramelPrimative::Text(Rc::new(String::new())).discriminant());
+    }
+
+    #[test]
+    fn string_pool_1() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+fonk ad: döndür 'karamel'
+fonk dil: döndür 'karamel'
+hataayıklama::doğrula(ad(), dil())
+"#, &mut context);
+        assert!(result.is_ok());
+
+        let mut texts = Vec::new();
+        for storage in context.storages.iter() {
+            for constant in storage.constants.iter() {
+                if let KaramelPrimative::Text(text) = &*constant.deref() {
+                    if text.as_str() == "karamel" {
+                        texts.push(text.clone());
+                    }
+                }
+            }
+        }
+
+        assert_eq!(texts.len(), 2);
+        assert!(Rc::ptr_eq(&texts[0], &texts[1]));
+    }
+
+    #[test]
+    fn native_callback_1() {
+        let mut context = KaramelCompilerContext::new();
+        context.register_module("sunucu", host_module());
+        let result = run_with_context(r#"
+sunucu yükle
+fonk artan(sol, sağ): döndür sol - sağ
+fonk azalan(sol, sağ):
+    fark = sağ - sol
+    döndür fark
+sayılar = [3, 1, 4, 2]
+hataayıklama::doğrula(sunucu::sırala(sayılar, artan), [1, 2, 3, 4])
+hataayıklama::doğrula(sunucu::sırala(sayılar, azalan), [4, 3, 2, 1])
+hataayıklama::doğrula(sayılar, [3, 1, 4, 2])
+"#, &mut context);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn native_callback_2() {
+        let mut context = KaramelCompilerContext::new();
+        context.register_module("sunucu", host_module());
+        let result = run_with_context(r#"
+sunucu yükle
+fonk hatalı(sol): döndür sol
+sunucu::sırala([2, 1], hatalı)
+"#, &mut context);
+        assert_eq!(result.err(), Some(KaramelErrorType::FunctionArgumentNotMatching {
+            function: "hatalı".to_string(),
+            expected: 1,
+            found: 2
+        }));
+    }
+
+    #[test]
+    fn max_stack_depth_1() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+fonk hesapla(a, b): döndür a + (b * (a - b))
+hataayıklama::doğrula(hesapla(3, 2), 5)
+"#, &mut context);
+        assert!(result.is_ok());
+
+        /* a, b, a and b are loaded before the first subtraction */
+        let function = context.get_function("hesapla".to_string(), &Vec::new(), 0).unwrap();
+        assert_eq!(function.max_stack_depth.get(), 4);
+    }
+
+    #[test]
+    fn backtrace_1() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+fonk iç(değer):
+    hataayıklama::doğrula(değer, 2)
+fonk dış(değer):
+    döndür iç(değer)
+dış(1)
+"#, &mut context);
+        assert!(result.is_err());
+        assert_eq!(context.call_stack().iter().map(|frame| frame.function.as_str()).collect::<Vec<_>>(), vec!["iç", "dış"]);
+    }
+
+    #[test]
+    fn backtrace_2() {
+        let status = executer::code_executer(ExecutionParameters {
+            source: ExecutionSource::Code(r#"
+fonk iç(değer):
+    hataayıklama::doğrula(değer, 2)
+fonk dış(değer):
+    döndür iç(değer)
+dış(1)
+"#.to_string()),
+            return_opcode: false,
+            return_output: true,
+            dump_opcode: false,
+            dump_memory: false
+        });
+
+        let error = status.error.unwrap();
+        assert_eq!(error.backtrace, vec![
+            KaramelStackFrame { function: "iç".to_string(), line: 4, column: 11 },
+            KaramelStackFrame { function: "dış".to_string(), line: 5, column: 0 }
+        ]);
+
+        let stderr = status.stderr.unwrap().into_inner();
+        assert!(stderr.contains("'iç' içinde"), "{}", stderr);
+        assert!(stderr.contains("'dış' tarafından çağrıldı [4:11]"), "{}", stderr);
+    }
+
+    /* Functions called from native functions get the location of the native call */
+    #[test]
+    fn backtrace_3() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+fonk iç(değer):
+    hataayıklama::doğrula(değer, 2)
+fonk dış(değer):
+    döndür [değer].hepsi(iç)
+dış(1)
+"#, &mut context);
+        assert!(result.is_err());
+        assert_eq!(context.call_stack(), vec![
+            KaramelStackFrame { function: "iç".to_string(), line: 4, column: 19 },
+            KaramelStackFrame { function: "dış".to_string(), line: 5, column: 0 }
+        ]);
+    }
+
+    #[test]
+    fn print_without_arguments() {
+        let status = executer::code_executer(ExecutionParameters {
+            source: ExecutionSource::Code(r#"
+gç::yaz()
+gç::satıryaz()
+gç::yaz()
+"#.to_string()),
+            return_opcode: false,
+            return_output: true,
+            dump_opcode: false,
+            dump_memory: false
+        });
+
+        assert!(status.executed);
+        assert_eq!(status.stdout.unwrap().into_inner(), "\r\n");
+    }
+
+    #[test]
+    fn execute_output_1() {
+        let output = executer::execute("gç::satıryaz('merhaba')");
+        assert_eq!(*output.result.unwrap().deref(), KaramelPrimative::Empty);
+        assert_eq!(output.stdout, "merhaba\r\n");
+        assert_eq!(output.stderr, "");
+    }
+
+    #[test]
+    fn execute_output_2() {
+        let output = executer::execute("gç::yaz('toplam: ', 1 + 2)\n10 * 2");
+        assert_eq!(*output.result.unwrap().deref(), KaramelPrimative::Number(20.0));
+        assert_eq!(output.stdout, "toplam: 3");
+    }
+
+    #[test]
+    fn execute_output_3() {
+        let output = executer::execute("gç::yaz('önce')\nhataayıklama::doğrula(1, 2)");
+        assert_eq!(output.stdout, "önce");
+        assert!(output.stderr.contains("Program hata ile sonlandırıldı"), "{}", output.stderr);
+        assert!(output.result.is_err());
+    }
+
+    #[test]
+    fn execute_output_4() {
+        let output = executer::execute("a = (");
+        assert!(output.result.is_err());
+        assert!(!output.stderr.is_empty());
+    }
+
+    #[test]
+    fn copy_to_store_1() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+c = 1
+d = ++c
+e = [--c, 10]
+hataayıklama::doğrula(c, 1)
+hataayıklama::doğrula(d, 2)
+hataayıklama::doğrula(e, [1, 10])
+"#, &mut context).unwrap();
+
+        /* Only the variables are at the stack, prefix results are consumed */
+        assert_eq!(result.len(), context.storages[0].variables.len());
+    }
+
+    #[test]
+    fn copy_to_store_2() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+c = 0
+sayaç = 0
+döngü sayaç != 100:
+    ++c
+    sayaç += 1
+hataayıklama::doğrula(c, 100)
+"#, &mut context).unwrap();
+
+        assert_eq!(result.len(), context.storages[0].variables.len());
+    }
+
+    fn run_opcodes(opcodes: Vec<u8>, constants: &[KaramelPrimative]) -> Vec<KaramelPrimative> {
+        let mut context = KaramelCompilerContext::new();
+        for constant in constants.iter() {
+            context.storages[0].add_constant(Rc::new(constant.clone()));
+        }
+
+        context.opcodes = opcodes;
+        context.opcodes_ptr = context.opcodes.as_mut_ptr();
+        context.opcodes_top_ptr = context.opcodes_ptr;
+
+        let result = unsafe { interpreter::run_vm(&mut context, false, false) }.unwrap();
+        result.iter().map(|object| (*object.deref()).clone()).collect()
+    }
+
+    #[test]
+    fn pop_1() {
+        let result = run_opcodes(vec![
+            VmOpCode::Constant.into(), 0,
+            VmOpCode::Constant.into(), 1,
+            VmOpCode::Pop.into(),
+            VmOpCode::Halt.into()
+        ], &[KaramelPrimative::Number(1.0), KaramelPrimative::Number(2.0)]);
+        assert_eq!(result, vec![KaramelPrimative::Number(1.0)]);
+    }
+
+    #[test]
+    fn swap_1() {
+        let result = run_opcodes(vec![
+            VmOpCode::Constant.into(), 0,
+            VmOpCode::Constant.into(), 1,
+            VmOpCode::Swap.into(),
+            VmOpCode::Halt.into()
+        ], &[KaramelPrimative::Number(1.0), KaramelPrimative::Text(Rc::new("karamel".to_string()))]);
+        assert_eq!(result, vec![KaramelPrimative::Text(Rc::new("karamel".to_string())), KaramelPrimative::Number(1.0)]);
+    }
+
+    #[test]
+    fn duplicate_1() {
+        let result = run_opcodes(vec![
+            VmOpCode::Constant.into(), 0,
+            VmOpCode::Duplicate.into(),
+            VmOpCode::Halt.into()
+        ], &[KaramelPrimative::Number(7.0)]);
+        assert_eq!(result, vec![KaramelPrimative::Number(7.0), KaramelPrimative::Number(7.0)]);
+    }
+
+    #[test]
+    fn expression_statement_1() {
+        let mut context = KaramelCompilerContext::new();
+        let result = run_with_context(r#"
+fonk hesapla(a): döndür a * 2
+a = 1
+sayaç = 0
+döngü sayaç != 1000:
+    1 + 2
+    a * 10
+    'karamel'
+    [a, 2]
+    {'a': a}
+    a == 1
+    !a
+    a++
+    hesapla(a)
+    a ise:
+        a - 1
+    sayaç += 1
+hataayıklama::doğrula(a, 1001)
+"#, &mut context).unwrap();
+
+        /* Only the variables are at the stack */
+        assert_eq!(result.len(), context.storages[0].variables.len());
+    }
+}